@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+    Throughput,
+};
+
+use write_only::prelude::*;
+
+const LEN: usize = 64 * 1024;
+
+fn bench_type<T>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, value: T)
+where
+    T: Copy + Default,
+{
+    let mut dst: Vec<T> = vec![T::default(); LEN];
+
+    group.bench_function(format!("{name}/write_fill"), |b| {
+        b.iter(|| {
+            WriteOnlySlice::from(&mut dst[..]).write_fill(black_box(value));
+            black_box(&mut dst);
+        })
+    });
+
+    group.bench_function(format!("{name}/fill"), |b| {
+        b.iter(|| {
+            WriteOnlySlice::from(&mut dst[..]).fill(black_box(value));
+            black_box(&mut dst);
+        })
+    });
+
+    group.bench_function(format!("{name}/slice_fill"), |b| {
+        b.iter(|| {
+            dst.fill(black_box(value));
+            black_box(&mut dst);
+        })
+    });
+}
+
+fn fill(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill");
+
+    group.throughput(Throughput::Elements(LEN as u64));
+
+    bench_type(&mut group, "u8", 0xAB_u8);
+    bench_type(&mut group, "u32", 0xDEAD_BEEF_u32);
+
+    group.finish();
+}
+
+criterion_group!(benches, fill);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::ptr;
+
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+    Throughput,
+};
+
+use write_only::{prelude::*, PutAt};
+
+const LEN: usize = 4 * 1024;
+
+#[derive(Copy, Clone, Default)]
+#[allow(dead_code)]
+struct Large([u64; 16]);
+
+fn bench_type<T>(group: &mut BenchmarkGroup<'_, WallTime>, name: &str, value: T)
+where
+    T: Copy + Default,
+{
+    let mut dst: Vec<T> = vec![T::default(); LEN];
+
+    group.bench_function(format!("{name}/put_at"), |b| {
+        b.iter(|| {
+            let mut slice = WriteOnlySlice::from(&mut dst[..]);
+            for index in 0..black_box(LEN) {
+                slice.put_at(index, value);
+            }
+            black_box(&mut dst);
+        })
+    });
+
+    group.bench_function(format!("{name}/assign"), |b| {
+        b.iter(|| {
+            let data = dst.as_mut_ptr();
+            for index in 0..black_box(LEN) {
+                unsafe { *data.add(index) = value };
+            }
+            black_box(&mut dst);
+        })
+    });
+
+    group.bench_function(format!("{name}/replace"), |b| {
+        b.iter(|| {
+            let data = dst.as_mut_ptr();
+            for index in 0..black_box(LEN) {
+                let _ = unsafe { ptr::replace(data.add(index), value) };
+            }
+            black_box(&mut dst);
+        })
+    });
+}
+
+fn put_at(c: &mut Criterion) {
+    let mut group = c.benchmark_group("put_at");
+
+    group.throughput(Throughput::Elements(LEN as u64));
+
+    bench_type(&mut group, "u8", 42_u8);
+    bench_type(&mut group, "u64", 42_u64);
+    bench_type(&mut group, "large", Large([42; 16]));
+
+    group.finish();
+}
+
+criterion_group!(benches, put_at);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use write_only::prelude::*;
+
+const SIZES: [usize; 3] = [4 * 1024, 1024 * 1024, 16 * 1024 * 1024];
+
+fn write_copying_from_slice_at(c: &mut Criterion) {
+    let mut group = c.benchmark_group("write_copying_from_slice_at");
+
+    for size in SIZES {
+        let src: Vec<u8> = (0..size).map(|i| i as u8).collect();
+        let mut dst: Vec<u8> = vec![0; size];
+
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("std", size), &src, |b, src| {
+            b.iter(|| {
+                dst[..].copy_from_slice(black_box(&src[..]));
+                black_box(&mut dst);
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("write_only", size), &src, |b, src| {
+            b.iter(|| {
+                let mut slice = WriteOnlySlice::from(&mut dst[..]);
+                slice.write_copying_from_slice_at(black_box(&src[..]), 0);
+                black_box(&mut dst);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, write_copying_from_slice_at);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use write_only::{prelude::*, WriteAt};
+
+const SIZES: [usize; 3] = [4 * 1024, 64 * 1024, 1024 * 1024];
+
+fn writer_unchecked(c: &mut Criterion) {
+    let mut group = c.benchmark_group("writer_unchecked");
+
+    for size in SIZES {
+        let mut dst: Vec<u32> = vec![0; size];
+
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("write_at", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut slice = WriteOnlySlice::from(&mut dst[..]);
+                for index in 0..black_box(size) {
+                    slice.write_at(index, index as u32);
+                }
+                black_box(&mut dst);
+            })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("write_at_unchecked", size),
+            &size,
+            |b, &size| {
+                b.iter(|| {
+                    let mut slice = WriteOnlySlice::from(&mut dst[..]);
+                    for index in 0..black_box(size) {
+                        unsafe { slice.write_at_unchecked(index, index as u32) };
+                    }
+                    black_box(&mut dst);
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("writer_unchecked", size),
+            &size,
+            |b, &size| {
+                b.iter(|| {
+                    let mut slice = WriteOnlySlice::from(&mut dst[..]);
+                    let mut writer = unsafe { slice.writer_unchecked() };
+                    for index in 0..black_box(size) {
+                        writer.write(index, index as u32);
+                    }
+                    black_box(&mut dst);
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, writer_unchecked);
+criterion_main!(benches);
@@ -0,0 +1,71 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Errors returned by fallible write operations.
+
+use core::fmt;
+
+/// An error returned when a write exceeds the capacity of a write-only slice.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct CapacityError {
+    /// The number of elements the write would have required the slice to have.
+    pub needed: usize,
+    /// The number of elements the slice actually has.
+    pub available: usize,
+}
+
+impl CapacityError {
+    #[inline]
+    pub(crate) fn check(needed: usize, available: usize) -> Result<(), Self> {
+        if needed <= available {
+            Ok(())
+        } else {
+            Err(Self { needed, available })
+        }
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "capacity exceeded: needed {}, available {}",
+            self.needed, self.available
+        )
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let error = CapacityError {
+            needed: 5,
+            available: 3,
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "capacity exceeded: needed 5, available 3"
+        );
+    }
+
+    #[test]
+    fn error() {
+        fn fallible() -> Result<(), Box<dyn core::error::Error>> {
+            CapacityError::check(5, 3)?;
+
+            Ok(())
+        }
+
+        assert_eq!(
+            fallible().unwrap_err().to_string(),
+            "capacity exceeded: needed 5, available 3"
+        );
+    }
+}
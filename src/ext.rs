@@ -0,0 +1,115 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Extension traits for obtaining write-only views of mutable borrows.
+
+use crate::{VolatileWriteOnlyRef, VolatileWriteOnlySlice, WriteOnlyRef, WriteOnlySlice};
+
+/// An extension trait for obtaining write-only views of values and slices.
+///
+/// Implemented for slices (yielding write-only slices), as well as for any sized
+/// value (yielding write-only references). Note that this includes containers
+/// such as `Vec<T>` or `[T; N]`, which thus need to be explicitly sliced
+/// (e.g. `vec[..].write_only()`) in order to obtain a write-only slice.
+///
+/// # Examples
+///
+/// ```
+/// use write_only::{prelude::*, PutAt};
+///
+/// let mut values: Vec<u8> = vec![0; 3];
+///
+/// values[..].write_only().put_at(1, 42);
+///
+/// assert_eq!(values, &[0, 42, 0]);
+/// ```
+pub trait AsWriteOnly {
+    /// The type of write-only view with **dropping non-volatile** write access.
+    type WriteOnly<'a>
+    where
+        Self: 'a;
+
+    /// The type of write-only view with **non-dropping volatile** write access.
+    type VolatileWriteOnly<'a>
+    where
+        Self: 'a;
+
+    /// Returns a write-only view with **dropping non-volatile** write access.
+    fn write_only(&mut self) -> Self::WriteOnly<'_>;
+
+    /// Returns a write-only view with **non-dropping volatile** write access.
+    fn write_only_volatile(&mut self) -> Self::VolatileWriteOnly<'_>;
+}
+
+impl<T> AsWriteOnly for [T] {
+    type WriteOnly<'a>
+        = WriteOnlySlice<'a, T>
+    where
+        T: 'a;
+    type VolatileWriteOnly<'a>
+        = VolatileWriteOnlySlice<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn write_only(&mut self) -> Self::WriteOnly<'_> {
+        WriteOnlySlice::from(self)
+    }
+
+    #[inline]
+    fn write_only_volatile(&mut self) -> Self::VolatileWriteOnly<'_> {
+        VolatileWriteOnlySlice::from(self)
+    }
+}
+
+impl<T> AsWriteOnly for T {
+    type WriteOnly<'a>
+        = WriteOnlyRef<'a, T>
+    where
+        T: 'a;
+    type VolatileWriteOnly<'a>
+        = VolatileWriteOnlyRef<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn write_only(&mut self) -> Self::WriteOnly<'_> {
+        WriteOnlyRef::from(self)
+    }
+
+    #[inline]
+    fn write_only_volatile(&mut self) -> Self::VolatileWriteOnly<'_> {
+        VolatileWriteOnlyRef::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Put, PutAt, Write, WriteAt};
+
+    #[test]
+    fn slice() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        values[..].write_only().put_at(0, 42);
+        values[..].write_only_volatile().write_at(2, 43);
+
+        assert_eq!(values, &[42, 1, 43]);
+    }
+
+    #[test]
+    fn value() {
+        let mut value = 0;
+
+        value.write_only().put(42);
+
+        assert_eq!(value, 42);
+
+        value.write_only_volatile().write(43);
+
+        assert_eq!(value, 43);
+    }
+}
@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Formatting into write-only byte slices.
+
+use core::fmt;
+
+use crate::WriteOnlySlice;
+
+/// A [`fmt::Write`] adapter, writing UTF-8 bytes into a write-only byte slice.
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+///
+/// use write_only::{prelude::*, FmtWriter};
+///
+/// let mut buffer = [0u8; 16];
+///
+/// let mut writer = FmtWriter::new(WriteOnlySlice::from(&mut buffer));
+/// write!(writer, "{}-{}", 4, 2).unwrap();
+/// let written = writer.written();
+///
+/// assert_eq!(&buffer[..written], b"4-2");
+/// ```
+pub struct FmtWriter<'a> {
+    slice: WriteOnlySlice<'a, u8>,
+    pos: usize,
+}
+
+impl<'a> FmtWriter<'a> {
+    /// Creates a writer, writing into `slice`, starting at its beginning.
+    #[inline]
+    pub fn new(slice: WriteOnlySlice<'a, u8>) -> Self {
+        Self { slice, pos: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.slice.len() - self.pos
+    }
+}
+
+impl<'a> fmt::Write for FmtWriter<'a> {
+    /// Writes `s` into the slice, failing without writing anything
+    /// if it does not fit into the slice's remaining bytes.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+
+        if bytes.len() > self.remaining() {
+            return Err(fmt::Error);
+        }
+
+        self.slice.write_copying_from_slice_at(bytes, self.pos);
+        self.pos += bytes.len();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::fmt::Write;
+
+    #[test]
+    fn write_str() {
+        let mut buffer = [0u8; 8];
+
+        let mut writer = FmtWriter::new(WriteOnlySlice::from(&mut buffer));
+        write!(writer, "{}+{}", 1, 2).unwrap();
+
+        assert_eq!(writer.written(), 3);
+        assert_eq!(writer.remaining(), 5);
+        assert_eq!(&buffer[..3], b"1+2");
+    }
+
+    #[test]
+    fn write_str_overflow() {
+        let mut buffer = [0u8; 4];
+
+        let mut writer = FmtWriter::new(WriteOnlySlice::from(&mut buffer));
+
+        assert!(write!(writer, "abc").is_ok());
+        assert!(write!(writer, "de").is_err());
+        assert_eq!(writer.written(), 3);
+
+        assert_eq!(&buffer, b"abc\0");
+    }
+}
@@ -37,22 +37,155 @@
 //!
 //! assert_eq!(values[2], 42u8);
 //! ```
+//!
+//! Initializing uninitialized memory (without dropping any of its garbage):
+//! ```
+//! use std::mem::MaybeUninit;
+//!
+//! use write_only::{prelude::*, WriteAt};
+//!
+//! let mut buffer: Box<[MaybeUninit<u8>]> = Box::new_uninit_slice(4);
+//!
+//! let mut write_only = WriteOnlySlice::from_uninit(&mut buffer[..]);
+//! for index in 0..write_only.len() {
+//!     write_only.write_at(index, MaybeUninit::new(index as u8 * 2));
+//! }
+//!
+//! // SAFETY: every element has just been written to.
+//! let buffer: Box<[u8]> = unsafe { buffer.assume_init() };
+//!
+//! assert_eq!(&buffer[..], &[0, 2, 4, 6]);
+//! ```
+//!
+//! # Features
+//!
+//! * `std` (default): Enables functionality depending on the standard library.
+//! * `raw` (default): Enables the `unsafe` raw-pointer constructors
+//!   (e.g. `WriteOnlySlice::from_raw_parts_mut`), as well as the type-erased `RawWriteOnlySlice`.
+//!   With this feature disabled the crate's public constructors are limited to safe
+//!   conversions from mutable borrows.
+//! * `safe`: Together with `raw` disabled, additionally removes the remaining `unsafe`
+//!   inherent methods (e.g. `project_unchecked`, `assume_readable`, `assume_init`,
+//!   `writer_unchecked`), leaving only safe constructors and methods.
+//!   (The unsafe `*_unchecked` methods of the `PutAt`/`WriteAt` traits remain,
+//!   as implementors of the traits have to provide them.)
+//!   Has no effect while `raw` is enabled.
+//! * `bytemuck`: Enables byte-views of write-only references to `bytemuck::Pod` values.
+//! * `derive`: Enables `#[derive(WriteOnlyFields)]`, generating per-field setters
+//!   for write-only references to structs. Implies `raw`, as the setters project via `project_unchecked`.
+//! * `cache-flush`: Makes `WriteOnlySlice::cache_flush_range` flush the CPU's data caches
+//!   on `x86`/`x86_64` (it falls back to a memory fence on other targets).
+//! * `debug-overlap-check`: In debug builds, tracks the memory ranges of all live write-only
+//!   slices formed via the raw-pointer constructors, panicking if two of them overlap.
+//!   Slices derived from those (e.g. via `split_at`) are not tracked themselves,
+//!   and a tracked slice that gets leaked (e.g. via `mem::forget`) stays registered.
+//!   Compiled out entirely in release builds.
+//! * `debug-no-inline`: Marks the volatile write methods as `#[inline(never)]`,
+//!   giving each store its own stack frame, e.g. for setting breakpoints on
+//!   individual register writes in a debugger.
+//!
+//! For the safe facade, depend on the crate with `default-features = false`
+//! and `features = ["std", "safe"]` (or just `["safe"]` for `no_std`).
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_docs)]
 
+mod error;
+mod ext;
+mod fmt;
+#[cfg(feature = "std")]
+mod owning;
+#[cfg(feature = "raw")]
+mod raw;
 mod reference;
 mod slice;
 
-pub use reference::{Put, VolatileWriteOnlyRef, Write, WriteOnlyRef};
+pub use error::CapacityError;
+pub use ext::AsWriteOnly;
+pub use fmt::FmtWriter;
+#[cfg(feature = "std")]
+pub use owning::OwningWriteOnly;
+#[cfg(feature = "raw")]
+pub use raw::RawWriteOnlySlice;
+pub use reference::{
+    tee, tee_write, write_only_store, AtomicWriteOnlyRef, Put, UnalignedWriteOnlyRef,
+    VolatileWriteOnlyRef, Write, WriteAck, WriteOnlyCellView, WriteOnlyRef,
+};
 pub use slice::{
-    PutAt, PutFromSliceAt, VolatileWriteOnlySlice, WriteAt, WriteFromSliceAt, WriteOnlySlice,
+    IntoIter, PutAt, PutFromSliceAt, UncheckedWriter, UninitWriteOnlySlice, VolatileIntoIter,
+    VolatileWriteOnlyGrid, VolatileWriteOnlySlice, WriteAt, WriteFromSliceAt,
+    WriteOnlyNonEmptySlice, WriteOnlySlice, WriteOnlySliceRef,
 };
+#[cfg(feature = "derive")]
+pub use write_only_derive::WriteOnlyFields;
 
 /// The crate's prelude.
+///
+/// A single `use write_only::prelude::*;` brings into scope:
+///
+/// * the write-only reference types ([`WriteOnlyRef`], [`VolatileWriteOnlyRef`], [`AtomicWriteOnlyRef`],
+///   [`UnalignedWriteOnlyRef`]),
+/// * the write-only slice types ([`WriteOnlySlice`], [`VolatileWriteOnlySlice`],
+///   [`WriteOnlyNonEmptySlice`], [`UninitWriteOnlySlice`], [`VolatileWriteOnlyGrid`],
+///   [`WriteOnlySliceRef`]),
+/// * the types returned by their methods ([`IntoIter`], [`VolatileIntoIter`], [`UncheckedWriter`],
+///   [`WriteAck`], [`WriteOnlyCellView`]),
+/// * the adapters [`FmtWriter`] and (with the `std` feature) [`OwningWriteOnly`],
+/// * and the traits [`Put`], [`Write`], [`PutAt`], [`PutFromSliceAt`], [`WriteAt`],
+///   [`WriteFromSliceAt`] and [`AsWriteOnly`], anonymously,
+///   i.e. making their methods callable without bringing their names into scope.
+///
+/// ```
+/// use write_only::prelude::*;
+///
+/// let mut values = [0_u8; 3];
+///
+/// let slice = WriteOnlySlice::from(&mut values);
+/// let iter: IntoIter<'_, u8> = slice.into_iter();
+///
+/// for (index, mut reference) in iter.enumerate() {
+///     reference.put(index as u8);
+/// }
+///
+/// assert_eq!(values, [0, 1, 2]);
+/// ```
+///
+/// [`WriteOnlyRef`]: crate::WriteOnlyRef
+/// [`VolatileWriteOnlyRef`]: crate::VolatileWriteOnlyRef
+/// [`AtomicWriteOnlyRef`]: crate::AtomicWriteOnlyRef
+/// [`UnalignedWriteOnlyRef`]: crate::UnalignedWriteOnlyRef
+/// [`WriteOnlySlice`]: crate::WriteOnlySlice
+/// [`VolatileWriteOnlySlice`]: crate::VolatileWriteOnlySlice
+/// [`WriteOnlyNonEmptySlice`]: crate::WriteOnlyNonEmptySlice
+/// [`WriteOnlySliceRef`]: crate::WriteOnlySliceRef
+/// [`UninitWriteOnlySlice`]: crate::UninitWriteOnlySlice
+/// [`VolatileWriteOnlyGrid`]: crate::VolatileWriteOnlyGrid
+/// [`IntoIter`]: crate::IntoIter
+/// [`VolatileIntoIter`]: crate::VolatileIntoIter
+/// [`UncheckedWriter`]: crate::UncheckedWriter
+/// [`WriteAck`]: crate::WriteAck
+/// [`WriteOnlyCellView`]: crate::WriteOnlyCellView
+/// [`FmtWriter`]: crate::FmtWriter
+/// [`OwningWriteOnly`]: crate::OwningWriteOnly
+/// [`Put`]: crate::Put
+/// [`Write`]: crate::Write
+/// [`PutAt`]: crate::PutAt
+/// [`PutFromSliceAt`]: crate::PutFromSliceAt
+/// [`WriteAt`]: crate::WriteAt
+/// [`WriteFromSliceAt`]: crate::WriteFromSliceAt
+/// [`AsWriteOnly`]: crate::AsWriteOnly
 pub mod prelude {
-    pub use crate::reference::{Put as _, VolatileWriteOnlyRef, Write as _, WriteOnlyRef};
+    pub use crate::ext::AsWriteOnly as _;
+    pub use crate::fmt::FmtWriter;
+    #[cfg(feature = "std")]
+    pub use crate::owning::OwningWriteOnly;
+    pub use crate::reference::{
+        AtomicWriteOnlyRef, Put as _, UnalignedWriteOnlyRef, VolatileWriteOnlyRef, Write as _,
+        WriteAck, WriteOnlyCellView, WriteOnlyRef,
+    };
     pub use crate::slice::{
-        PutAt as _, PutFromSliceAt as _, VolatileWriteOnlySlice, WriteAt as _,
-        WriteFromSliceAt as _, WriteOnlySlice,
+        IntoIter, PutAt as _, PutFromSliceAt as _, UncheckedWriter, UninitWriteOnlySlice,
+        VolatileIntoIter, VolatileWriteOnlyGrid, VolatileWriteOnlySlice, WriteAt as _,
+        WriteFromSliceAt as _, WriteOnlyNonEmptySlice, WriteOnlySlice, WriteOnlySliceRef,
     };
 }
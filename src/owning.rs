@@ -0,0 +1,190 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Buffers that own their values, while only providing write-access, no read.
+
+use crate::{PutAt, PutFromSliceAt, WriteAt, WriteFromSliceAt, WriteOnlySlice};
+
+/// A write-only **owning buffer** with **dropping non-volatile** write access.
+///
+/// Unlike [`WriteOnlySlice`], which merely borrows its values,
+/// the buffer owns its values and drops them when it gets dropped itself.
+pub struct OwningWriteOnly<T> {
+    values: Box<[T]>,
+}
+
+impl<T> OwningWriteOnly<T> {
+    /// Returns the number of elements in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the buffer has a length of 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns a write-only slice of the buffer's values.
+    #[inline]
+    pub fn as_write_only(&mut self) -> WriteOnlySlice<'_, T> {
+        WriteOnlySlice::from(&mut self.values[..])
+    }
+}
+
+impl<T> PutAt<T> for OwningWriteOnly<T> {
+    #[inline]
+    #[track_caller]
+    fn put_at(&mut self, index: usize, value: T) {
+        self.as_write_only().put_at(index, value);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn put_at_unchecked(&mut self, index: usize, value: T) {
+        self.as_write_only().put_at_unchecked(index, value);
+    }
+}
+
+impl<T> WriteAt<T> for OwningWriteOnly<T> {
+    #[inline]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        self.as_write_only().write_at(index, value);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        self.as_write_only().write_at_unchecked(index, value);
+    }
+}
+
+impl<T> PutFromSliceAt<T> for OwningWriteOnly<T> {
+    #[inline]
+    #[track_caller]
+    fn put_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Clone,
+    {
+        self.as_write_only().put_cloning_from_slice_at(src, offset);
+    }
+
+    #[inline]
+    #[track_caller]
+    fn put_copying_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Copy,
+    {
+        self.as_write_only().put_copying_from_slice_at(src, offset);
+    }
+}
+
+impl<T> WriteFromSliceAt<T> for OwningWriteOnly<T> {
+    #[inline]
+    #[track_caller]
+    fn write_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Clone,
+    {
+        self.as_write_only()
+            .write_cloning_from_slice_at(src, offset);
+    }
+
+    #[inline]
+    #[track_caller]
+    fn write_copying_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Copy,
+    {
+        self.as_write_only()
+            .write_copying_from_slice_at(src, offset);
+    }
+}
+
+impl<T> From<Box<[T]>> for OwningWriteOnly<T> {
+    #[inline]
+    fn from(values: Box<[T]>) -> Self {
+        Self { values }
+    }
+}
+
+impl<T> From<Vec<T>> for OwningWriteOnly<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        Self::from(values.into_boxed_slice())
+    }
+}
+
+impl<T> From<Box<T>> for OwningWriteOnly<T> {
+    /// Converts a boxed value into a single-element buffer.
+    #[inline]
+    fn from(value: Box<T>) -> Self {
+        // SAFETY: `T` has the same layout as `[T; 1]`.
+        let values: Box<[T; 1]> = unsafe { Box::from_raw(Box::into_raw(value) as *mut [T; 1]) };
+
+        Self::from(values as Box<[T]>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use droptest::prelude::*;
+
+    #[test]
+    fn drop() {
+        let registry = DropRegistry::default();
+        let guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let buffer = OwningWriteOnly::from(guards);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+
+        std::mem::drop(buffer);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    }
+
+    #[test]
+    fn put_at() {
+        let registry = DropRegistry::default();
+        let (old_ids, guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut buffer = OwningWriteOnly::from(guards);
+        buffer.put_at(1, new_guard);
+
+        assert_drop!(registry, old_ids[1]);
+        assert_drop_stats!(registry, { created: 4, dropped: 1 });
+
+        std::mem::drop(buffer);
+
+        assert_drop!(registry, new_id);
+        assert_drop_stats!(registry, { created: 4, dropped: 4 });
+    }
+
+    #[test]
+    fn from_box() {
+        let registry = DropRegistry::default();
+        let (old_id, guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let mut buffer = OwningWriteOnly::from(Box::new(guard));
+
+        assert_eq!(buffer.len(), 1);
+
+        buffer.put_at(0, new_guard);
+
+        assert_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+
+        std::mem::drop(buffer);
+
+        assert_drop!(registry, new_id);
+    }
+}
@@ -0,0 +1,182 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Type-erased write-only slices, for elements whose layout is only known at run-time.
+
+use core::marker::PhantomData;
+
+/// A type-erased write-only **slice** with **non-dropping non-volatile** write access,
+/// whose elements are `elem_size` bytes each.
+///
+/// This is the dynamically laid out cousin of [`WriteOnlySlice`](crate::WriteOnlySlice),
+/// for buffers whose element type is only known at run-time (e.g. via a `Layout`).
+///
+/// As the element type is erased, the slice can neither drop old values,
+/// nor check the written bytes for being valid values of the element type.
+/// Upholding the latter is up to the caller of [`write_raw_at`](Self::write_raw_at).
+///
+/// # Examples
+///
+/// ```
+/// use write_only::RawWriteOnlySlice;
+///
+/// let mut values = [0_u32; 3];
+///
+/// let mut slice = unsafe {
+///     RawWriteOnlySlice::from_raw_parts_mut(values.as_mut_ptr().cast(), 3, 4)
+/// };
+///
+/// // SAFETY: any four bytes are a valid `u32`.
+/// unsafe { slice.write_raw_at(1, &42_u32.to_ne_bytes()) };
+///
+/// assert_eq!(values, [0, 42, 0]);
+/// ```
+pub struct RawWriteOnlySlice<'a> {
+    data: *mut u8,
+    len: usize,
+    elem_size: usize,
+    _phantom: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> RawWriteOnlySlice<'a> {
+    /// Forms a type-erased write-only slice of `len` elements of `elem_size` bytes each,
+    /// starting at `data`.
+    ///
+    /// # Safety
+    ///
+    /// Behavior is undefined if any of the following conditions are violated:
+    ///
+    /// * `data` must be non-null and [valid](http://doc.rust-lang.org/core/ptr/index.html#safety)
+    ///   for writes for `len * elem_size` many bytes, all within a single allocated object.
+    ///
+    /// * `data` must point to `len` consecutive elements of the (erased) element type,
+    ///   whose size must be exactly `elem_size` bytes.
+    ///
+    /// * The memory referenced by the returned slice must not be accessed through
+    ///   any other pointer for the duration of lifetime `'a`.
+    ///
+    /// * The total size `len * elem_size` of the slice must be no larger than `isize::MAX`.
+    ///
+    /// # Caveat
+    ///
+    /// The lifetime for the returned slice is inferred from its usage. To
+    /// prevent accidental misuse, it's suggested to tie the lifetime to whichever
+    /// source lifetime is safe in the context, such as by providing a helper
+    /// function taking the lifetime of a host value for the slice, or by explicit
+    /// annotation.
+    #[inline]
+    pub unsafe fn from_raw_parts_mut(data: *mut u8, len: usize, elem_size: usize) -> Self {
+        debug_assert!(!data.is_null(), "attempt to create null slice");
+        debug_assert!(
+            elem_size
+                .checked_mul(len)
+                .is_some_and(|size| size <= isize::MAX as usize),
+            "attempt to create slice covering at least half the address space"
+        );
+
+        Self {
+            data,
+            len,
+            elem_size,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the size of each element in bytes.
+    #[inline]
+    pub fn elem_size(&self) -> usize {
+        self.elem_size
+    }
+
+    /// Returns the total size of the slice in bytes.
+    #[inline]
+    pub fn byte_len(&self) -> usize {
+        self.len * self.elem_size
+    }
+
+    /// Copies the `elem_size` bytes of `src` into the element at `index`,
+    /// i.e. to the bytes `[index * elem_size, (index + 1) * elem_size)` of the slice,
+    /// without dropping the old value.
+    ///
+    /// # Safety
+    ///
+    /// `src` must be a valid representation of a value of the (erased) element type
+    /// (e.g. a `bool` must only ever be `0` or `1`), and it must be valid for that value's old bytes to be overwritten
+    /// without running its destructor (which merely leaks it).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds, or if `src.len()` is not `elem_size`.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn write_raw_at(&mut self, index: usize, src: &[u8]) {
+        crate::slice::assert_index_in_bounds(index, self.len);
+        assert!(
+            src.len() == self.elem_size,
+            "source slice length does not match element size"
+        );
+
+        // SAFETY: `[index * elem_size, (index + 1) * elem_size)` is in-bounds,
+        // and `src` cannot overlap with `self`, whose memory is not accessible otherwise.
+        self.data
+            .add(index * self.elem_size)
+            .copy_from_nonoverlapping(src.as_ptr(), self.elem_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_raw_at() {
+        let mut values = [[0_u8; 3]; 4];
+
+        let mut slice =
+            unsafe { RawWriteOnlySlice::from_raw_parts_mut(values.as_mut_ptr().cast(), 4, 3) };
+
+        assert_eq!(slice.len(), 4);
+        assert_eq!(slice.elem_size(), 3);
+        assert_eq!(slice.byte_len(), 12);
+
+        unsafe {
+            slice.write_raw_at(0, &[1, 2, 3]);
+            slice.write_raw_at(3, &[4, 5, 6]);
+        }
+
+        assert_eq!(values, [[1, 2, 3], [0, 0, 0], [0, 0, 0], [4, 5, 6]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 out of bounds for write-only slice of length 2")]
+    fn write_raw_at_out_of_bounds() {
+        let mut values = [0_u16; 2];
+
+        let mut slice =
+            unsafe { RawWriteOnlySlice::from_raw_parts_mut(values.as_mut_ptr().cast(), 2, 2) };
+        unsafe { slice.write_raw_at(2, &[1, 2]) };
+    }
+
+    #[test]
+    #[should_panic(expected = "source slice length does not match element size")]
+    fn write_raw_at_size_mismatch() {
+        let mut values = [0_u16; 2];
+
+        let mut slice =
+            unsafe { RawWriteOnlySlice::from_raw_parts_mut(values.as_mut_ptr().cast(), 2, 2) };
+        unsafe { slice.write_raw_at(0, &[1, 2, 3]) };
+    }
+}
@@ -4,20 +4,354 @@
 
 //! References that only provide write-access, no read.
 
+use core::mem;
+
+mod atomic;
+mod cell_view;
 mod non_volatile;
+mod unaligned;
 mod volatile;
 
+pub use atomic::{write_only_store, AtomicWriteOnlyRef};
+pub use cell_view::WriteOnlyCellView;
 pub use non_volatile::WriteOnlyRef;
+pub use unaligned::UnalignedWriteOnlyRef;
 pub use volatile::VolatileWriteOnlyRef;
 
+/// A zero-sized token proving that a write through a write-only reference took place.
+///
+/// A `WriteAck` can only be obtained by consuming a write-only reference
+/// via one of its `write_acked` methods, so a function returning a `WriteAck`
+/// can only do so by having performed such a write:
+///
+/// ```
+/// use write_only::{VolatileWriteOnlyRef, WriteAck};
+///
+/// fn enable(control: VolatileWriteOnlyRef<'_, u32>) -> WriteAck {
+///     control.write_acked(0b1)
+/// }
+///
+/// let mut control = 0_u32;
+///
+/// let _ack = enable(VolatileWriteOnlyRef::from(&mut control));
+///
+/// assert_eq!(control, 0b1);
+/// ```
+#[must_use = "a `WriteAck` is meant to be returned as proof of the write"]
+#[derive(Debug)]
+pub struct WriteAck {
+    _private: (),
+}
+
+impl WriteAck {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
 /// A trait for objects which provide **dropping** write access to their value.
 pub trait Put<T> {
     /// Puts the value the given value, dropping the old value.
     fn put(&mut self, value: T);
+
+    /// Puts the value the given value, dropping the old value, if `cond` is `true`.
+    ///
+    /// If `cond` is `false` the given value gets dropped instead.
+    #[inline]
+    fn put_if(&mut self, cond: bool, value: T) {
+        if cond {
+            self.put(value);
+        }
+    }
+
+    /// Puts the value returned by `f`, dropping the old value.
+    ///
+    /// `f` is called at the time of the write, allowing generic code to defer
+    /// (or skip) computing the value.
+    #[inline]
+    fn put_with<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> T,
+        Self: Sized,
+    {
+        self.put(f());
+    }
 }
 
 /// A trait for objects which provide **non-dropping** write access to their value.
 pub trait Write<T> {
     /// Writes the value the given value without dropping the old value.
     fn write(&mut self, value: T);
+
+    /// Writes the value the given value without dropping the old value, if `cond` is `true`.
+    ///
+    /// If `cond` is `false` the given value gets forgotten (i.e. not dropped) instead.
+    #[inline]
+    fn write_if(&mut self, cond: bool, value: T) {
+        if cond {
+            self.write(value);
+        } else {
+            mem::forget(value);
+        }
+    }
+
+    /// Writes the value returned by `f` without dropping the old value.
+    ///
+    /// `f` is called at the time of the write, allowing generic code to defer
+    /// (or skip) computing the value.
+    #[inline]
+    fn write_with<F>(&mut self, f: F)
+    where
+        F: FnOnce() -> T,
+        Self: Sized,
+    {
+        self.write(f());
+    }
+}
+
+impl<T, P> Put<T> for &mut P
+where
+    P: Put<T> + ?Sized,
+{
+    #[inline]
+    fn put(&mut self, value: T) {
+        (**self).put(value);
+    }
+
+    #[inline]
+    fn put_if(&mut self, cond: bool, value: T) {
+        (**self).put_if(cond, value);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, P> Put<T> for Box<P>
+where
+    P: Put<T> + ?Sized,
+{
+    #[inline]
+    fn put(&mut self, value: T) {
+        (**self).put(value);
+    }
+
+    #[inline]
+    fn put_if(&mut self, cond: bool, value: T) {
+        (**self).put_if(cond, value);
+    }
+}
+
+impl<T, W> Write<T> for &mut W
+where
+    W: Write<T> + ?Sized,
+{
+    #[inline]
+    fn write(&mut self, value: T) {
+        (**self).write(value);
+    }
+
+    #[inline]
+    fn write_if(&mut self, cond: bool, value: T) {
+        (**self).write_if(cond, value);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, W> Write<T> for Box<W>
+where
+    W: Write<T> + ?Sized,
+{
+    #[inline]
+    fn write(&mut self, value: T) {
+        (**self).write(value);
+    }
+
+    #[inline]
+    fn write_if(&mut self, cond: bool, value: T) {
+        (**self).write_if(cond, value);
+    }
+}
+
+/// Returns a sink that puts each value into both `a` and `b`, dropping their old values.
+///
+/// `a` receives a clone of the value, `b` the value itself.
+///
+/// ```
+/// use write_only::{prelude::*, Put};
+///
+/// let (mut shadow, mut register) = (0_u32, 0_u32);
+///
+/// {
+///     let mut shadow_ref = WriteOnlyRef::from(&mut shadow);
+///     let mut register_ref = WriteOnlyRef::from(&mut register);
+///
+///     let mut sink = write_only::tee(&mut shadow_ref, &mut register_ref);
+///     sink.put(42);
+/// }
+///
+/// assert_eq!((shadow, register), (42, 42));
+/// ```
+#[inline]
+pub fn tee<'a, T>(a: &'a mut dyn Put<T>, b: &'a mut dyn Put<T>) -> impl Put<T> + 'a
+where
+    T: Clone + 'a,
+{
+    TeePut { a, b }
+}
+
+/// Returns a sink that writes each value into both `a` and `b`, without dropping their old values.
+///
+/// As `T` is `Copy` the value gets copied, rather than cloned.
+#[inline]
+pub fn tee_write<'a, T>(a: &'a mut dyn Write<T>, b: &'a mut dyn Write<T>) -> impl Write<T> + 'a
+where
+    T: Copy + 'a,
+{
+    TeeWrite { a, b }
+}
+
+struct TeePut<'a, T> {
+    a: &'a mut dyn Put<T>,
+    b: &'a mut dyn Put<T>,
+}
+
+impl<'a, T: Clone> Put<T> for TeePut<'a, T> {
+    #[inline]
+    fn put(&mut self, value: T) {
+        self.a.put(value.clone());
+        self.b.put(value);
+    }
+}
+
+struct TeeWrite<'a, T> {
+    a: &'a mut dyn Write<T>,
+    b: &'a mut dyn Write<T>,
+}
+
+impl<'a, T: Copy> Write<T> for TeeWrite<'a, T> {
+    #[inline]
+    fn write(&mut self, value: T) {
+        self.a.write(value);
+        self.b.write(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use droptest::prelude::*;
+
+    use super::*;
+
+    fn put_into<P: Put<u32>>(mut sink: P, value: u32) {
+        sink.put(value);
+    }
+
+    fn write_into<W: Write<u32>>(mut sink: W, value: u32) {
+        sink.write(value);
+    }
+
+    #[test]
+    fn put_dyn() {
+        let mut first = 0;
+        let mut second = 0;
+
+        {
+            let mut sinks: Vec<Box<dyn Put<u32>>> = vec![
+                Box::new(WriteOnlyRef::from(&mut first)),
+                Box::new(WriteOnlyRef::from(&mut second)),
+            ];
+
+            for (index, sink) in sinks.iter_mut().enumerate() {
+                put_into(sink, index as u32 + 1);
+            }
+        }
+
+        assert_eq!((first, second), (1, 2));
+    }
+
+    #[test]
+    fn write_dyn() {
+        let mut first = 0;
+        let mut second = 0;
+
+        {
+            let mut non_volatile = WriteOnlyRef::from(&mut first);
+            let mut volatile = VolatileWriteOnlyRef::from(&mut second);
+
+            let sinks: [&mut dyn Write<u32>; 2] = [&mut non_volatile, &mut volatile];
+
+            for (index, sink) in sinks.into_iter().enumerate() {
+                write_into(sink, index as u32 + 1);
+            }
+
+            write_into(Box::new(non_volatile) as Box<dyn Write<u32>>, 3);
+        }
+
+        assert_eq!((first, second), (3, 2));
+    }
+
+    #[test]
+    fn tee() {
+        let registry = DropRegistry::default();
+        let (first_old_id, mut first) = registry.new_guard_for(1).by_id();
+        let (second_old_id, mut second) = registry.new_guard_for(2).by_id();
+
+        {
+            let mut first_ref = WriteOnlyRef::from(&mut first);
+            let mut second_ref = WriteOnlyRef::from(&mut second);
+
+            let mut sink = super::tee(&mut first_ref, &mut second_ref);
+            sink.put(registry.new_guard_for(42));
+        }
+
+        assert_eq!((first.value(), second.value()), (&42, &42));
+
+        assert_drop!(registry, first_old_id);
+        assert_drop!(registry, second_old_id);
+        assert_drop_stats!(registry, { created: 4, dropped: 2 });
+    }
+
+    #[test]
+    fn tee_write() {
+        let mut shadow = 0_u32;
+        let mut register = 0_u32;
+
+        {
+            let mut shadow_ref = WriteOnlyRef::from(&mut shadow);
+            let mut register_ref = VolatileWriteOnlyRef::from(&mut register);
+
+            let mut sink = super::tee_write(&mut shadow_ref, &mut register_ref);
+            sink.write(1);
+            sink.write_if(false, 2);
+            sink.write(3);
+        }
+
+        assert_eq!((shadow, register), (3, 3));
+    }
+
+    #[test]
+    fn put_with_dyn() {
+        let mut value = 0;
+        let mut calls = 0;
+
+        {
+            let mut reference = WriteOnlyRef::from(&mut value);
+            let mut sink: &mut dyn Put<u32> = &mut reference;
+
+            let mut deferred = |cond: bool| {
+                if cond {
+                    // Called on `&mut dyn Put<u32>`, as `put_with` requires `Self: Sized`.
+                    Put::put_with(&mut sink, || {
+                        calls += 1;
+                        42
+                    });
+                }
+            };
+
+            deferred(false);
+            deferred(true);
+        }
+
+        assert_eq!((value, calls), (42, 1));
+    }
 }
@@ -0,0 +1,178 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+// On targets without atomics (e.g. MSP430) there is no way to obtain an `AtomicWriteOnlyRef`.
+#![cfg_attr(not(target_has_atomic = "8"), allow(dead_code, unused_imports))]
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{self, Ordering},
+};
+
+use crate::Write;
+
+/// A write-only **reference** to an atomic integer, with **atomic store-only** access.
+///
+/// Unlike the atomic it refers to, the reference provides no way of loading the value
+/// (nor of any read-modify-write operation), enforcing a "publish, but never observe"
+/// discipline on its holders.
+///
+/// As atomics are shared rather than borrowed mutably,
+/// the reference is `Copy`, just like a `&'a AtomicU32` would be.
+///
+/// # Examples
+///
+/// ```
+/// use core::sync::atomic::{AtomicU32, Ordering};
+///
+/// use write_only::{prelude::*, AtomicWriteOnlyRef};
+///
+/// let atomic = AtomicU32::new(0);
+///
+/// let producer: AtomicWriteOnlyRef<'_, u32> = write_only::write_only_store(&atomic);
+/// producer.store(42, Ordering::Release);
+///
+/// assert_eq!(atomic.load(Ordering::Acquire), 42);
+/// ```
+pub struct AtomicWriteOnlyRef<'a, T> {
+    // Points to the atomic counterpart of `T`, which has the same size as `T`.
+    data: *const T,
+    _phantom: PhantomData<&'a T>,
+}
+
+/// Returns a write-only reference with **atomic store-only** access to `atomic`.
+///
+/// Equivalent to `AtomicWriteOnlyRef::from(atomic)`.
+#[inline]
+pub fn write_only_store<'a, A, T>(atomic: &'a A) -> AtomicWriteOnlyRef<'a, T>
+where
+    &'a A: Into<AtomicWriteOnlyRef<'a, T>>,
+{
+    atomic.into()
+}
+
+impl<'a, T> Clone for AtomicWriteOnlyRef<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for AtomicWriteOnlyRef<'a, T> {}
+
+// SAFETY: the type provides the same (albeit store-only) access as the `&'a` atomic it was created from,
+// and atomics are `Sync`.
+unsafe impl<'a, T> Send for AtomicWriteOnlyRef<'a, T> {}
+
+// SAFETY: the type provides the same (albeit store-only) access as the `&'a` atomic it was created from,
+// and atomics are `Sync`.
+unsafe impl<'a, T> Sync for AtomicWriteOnlyRef<'a, T> {}
+
+macro_rules! impl_atomic_write_only_ref {
+    ($($width:literal => $int:ty, $atomic:ident;)*) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl<'a> AtomicWriteOnlyRef<'a, $int> {
+                #[doc = concat!("Stores `value` into the atomic, as if via [`core::sync::atomic::", stringify!($atomic), "::store`].")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if `order` is `Acquire` or `AcqRel`.
+                #[inline]
+                #[track_caller]
+                pub fn store(&self, value: $int, order: Ordering) {
+                    // SAFETY: the pointer was obtained from a reference to the atomic,
+                    // which is borrowed for `'a`.
+                    unsafe { (*(self.data as *const atomic::$atomic)).store(value, order) }
+                }
+            }
+
+            #[cfg(target_has_atomic = $width)]
+            impl<'a> From<&'a atomic::$atomic> for AtomicWriteOnlyRef<'a, $int> {
+                #[inline]
+                fn from(atomic: &'a atomic::$atomic) -> Self {
+                    Self {
+                        data: atomic.as_ptr() as *const $int,
+                        _phantom: PhantomData,
+                    }
+                }
+            }
+
+            /// Stores the value with [`Ordering::SeqCst`].
+            #[cfg(target_has_atomic = $width)]
+            impl<'a> Write<$int> for AtomicWriteOnlyRef<'a, $int> {
+                #[inline]
+                fn write(&mut self, value: $int) {
+                    self.store(value, Ordering::SeqCst);
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_write_only_ref! {
+    "8" => u8, AtomicU8;
+    "8" => i8, AtomicI8;
+    "16" => u16, AtomicU16;
+    "16" => i16, AtomicI16;
+    "32" => u32, AtomicU32;
+    "32" => i32, AtomicI32;
+    "64" => u64, AtomicU64;
+    "64" => i64, AtomicI64;
+    "ptr" => usize, AtomicUsize;
+    "ptr" => isize, AtomicIsize;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicI64, AtomicU8};
+
+    use super::*;
+
+    #[test]
+    fn store() {
+        let atomic = AtomicU8::new(0);
+
+        let reference = write_only_store(&atomic);
+        let copy = reference;
+
+        reference.store(1, Ordering::Relaxed);
+        assert_eq!(atomic.load(Ordering::Relaxed), 1);
+
+        copy.store(2, Ordering::Release);
+        assert_eq!(atomic.load(Ordering::Acquire), 2);
+    }
+
+    #[test]
+    fn write() {
+        let atomic = AtomicI64::new(0);
+
+        let mut reference = AtomicWriteOnlyRef::from(&atomic);
+        reference.write(-42);
+
+        assert_eq!(atomic.load(Ordering::SeqCst), -42);
+    }
+
+    #[test]
+    #[should_panic(expected = "there is no such thing as an acquire store")]
+    fn store_invalid_ordering() {
+        let atomic = AtomicU8::new(0);
+
+        let reference = write_only_store(&atomic);
+        reference.store(1, Ordering::Acquire);
+    }
+
+    #[test]
+    fn store_across_threads() {
+        let atomic = AtomicU8::new(0);
+
+        let reference = write_only_store(&atomic);
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || reference.store(42, Ordering::Release));
+        });
+
+        assert_eq!(atomic.load(Ordering::Acquire), 42);
+    }
+}
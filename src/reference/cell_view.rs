@@ -0,0 +1,249 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    ptr,
+};
+
+use crate::{PutAt, WriteAt};
+
+/// A shared view with **dropping non-volatile** write access to a value,
+/// obtained via [`WriteOnlyRef::as_write_cell`](crate::WriteOnlyRef::as_write_cell),
+/// or from a [`Cell`] via [`from_cell`](Self::from_cell) and [`from_cell_slice`](Self::from_cell_slice).
+///
+/// Much like a [`Cell<T>`](core::cell::Cell) that only allows for [`set`](Self::set)ting
+/// its value, a `&WriteOnlyCellView<T>` can be handed to multiple borrowers at once,
+/// each of them being able to write to the value.
+///
+/// ```
+/// use write_only::{prelude::*, WriteOnlyCellView};
+///
+/// fn reset(cell: &WriteOnlyCellView<u32>) {
+///     cell.set(0);
+/// }
+///
+/// fn bump(cell: &WriteOnlyCellView<u32>) {
+///     cell.set(42);
+/// }
+///
+/// let mut value = 1_u32;
+///
+/// let mut reference = WriteOnlyRef::from(&mut value);
+/// let cell = reference.as_write_cell();
+/// let (first, second) = (cell, cell);
+///
+/// reset(first);
+/// bump(second);
+///
+/// assert_eq!(value, 42);
+/// ```
+///
+/// # Soundness
+///
+/// Writing through a shared reference is sound here for the same reasons it is for `Cell<T>`:
+///
+/// * The view is obtained either from a `&mut WriteOnlyRef<'_, T>`, i.e. from exclusive access
+///   to the value, which stays borrowed for as long as the view is in use,
+///   or from a `&Cell<T>`, which already permits writes through shared references.
+/// * The value is wrapped in an [`UnsafeCell`], making the view `!Sync`,
+///   so that all of its (shared) borrowers are confined to a single thread,
+///   which rules out data races.
+/// * The view never reads the value, nor hands out references into it,
+///   so no borrower can observe a value being replaced underneath it.
+/// * The old value is moved out before being dropped, so its `Drop` impl
+///   never runs while a write is still in progress.
+#[repr(transparent)]
+pub struct WriteOnlyCellView<T> {
+    value: UnsafeCell<T>,
+}
+
+impl<T> WriteOnlyCellView<T> {
+    #[inline]
+    pub(crate) unsafe fn from_ptr<'a>(data: *mut T) -> &'a Self {
+        // SAFETY: `WriteOnlyCellView<T>` is `repr(transparent)` over `UnsafeCell<T>`,
+        // which has the same in-memory representation as `T`.
+        &*(data as *const Self)
+    }
+
+    /// Returns a write-only view of the value of `cell`.
+    #[inline]
+    pub fn from_cell(cell: &Cell<T>) -> &Self {
+        // SAFETY: `Cell<T>` has the same in-memory representation as `UnsafeCell<T>`,
+        // and permits writes through shared references just like the view does.
+        unsafe { Self::from_ptr(cell.as_ptr()) }
+    }
+
+    /// Returns a slice of write-only views of the elements of `cell`.
+    ///
+    /// The slice implements [`PutAt`] and [`WriteAt`], allowing for
+    /// indexed writes to the elements through the shared reference:
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// use write_only::{PutAt, WriteAt, WriteOnlyCellView};
+    ///
+    /// let cell: &Cell<[u8]> = &Cell::new([0_u8; 3]);
+    ///
+    /// let mut first = WriteOnlyCellView::from_cell_slice(cell);
+    /// let mut second = WriteOnlyCellView::from_cell_slice(cell);
+    ///
+    /// first.put_at(0, 1);
+    /// second.write_at(2, 3);
+    ///
+    /// let values: Vec<u8> = cell.as_slice_of_cells().iter().map(Cell::get).collect();
+    /// assert_eq!(values, [1, 0, 3]);
+    /// ```
+    #[inline]
+    pub fn from_cell_slice(cell: &Cell<[T]>) -> &[Self] {
+        // SAFETY: `Cell<[T]>` has the same in-memory representation as `[UnsafeCell<T>]`
+        // (see `Cell::as_slice_of_cells`), and thus as `[WriteOnlyCellView<T>]`.
+        unsafe { &*(cell as *const Cell<[T]> as *const [Self]) }
+    }
+
+    /// Sets the value to the given value, dropping the old value.
+    #[inline]
+    pub fn set(&self, value: T) {
+        // SAFETY: the view is `!Sync` and never hands out references into the value,
+        // so no other access to the value can be happening concurrently.
+        let old = unsafe { ptr::replace(self.value.get(), value) };
+
+        drop(old);
+    }
+
+    /// Writes the given value without dropping the old value.
+    #[inline]
+    pub fn write(&self, value: T) {
+        // SAFETY: the view is `!Sync` and never hands out references into the value,
+        // so no other access to the value can be happening concurrently.
+        unsafe { ptr::write(self.value.get(), value) };
+    }
+}
+
+impl<'a, T: 'a> PutAt<T> for &'a [WriteOnlyCellView<T>] {
+    #[inline]
+    #[track_caller]
+    fn put_at(&mut self, index: usize, value: T) {
+        crate::slice::assert_index_in_bounds(index, self.len());
+
+        // SAFETY: `index` was checked to be within bounds.
+        unsafe { self.put_at_unchecked(index, value) };
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn put_at_unchecked(&mut self, index: usize, value: T) {
+        debug_assert!(index < self.len(), "index out of bounds");
+
+        self.get_unchecked(index).set(value);
+    }
+}
+
+impl<'a, T: 'a> WriteAt<T> for &'a [WriteOnlyCellView<T>] {
+    #[inline]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        crate::slice::assert_index_in_bounds(index, self.len());
+
+        // SAFETY: `index` was checked to be within bounds.
+        unsafe { self.write_at_unchecked(index, value) };
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        debug_assert!(index < self.len(), "index out of bounds");
+
+        self.get_unchecked(index).write(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use droptest::prelude::*;
+
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::WriteOnlyRef;
+
+    #[test]
+    fn set() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (first_id, first_guard) = registry.new_guard_for(2).by_id();
+        let (second_id, second_guard) = registry.new_guard_for(3).by_id();
+
+        let mut reference = WriteOnlyRef::from(&mut guard);
+        let first = reference.as_write_cell();
+        let second = first;
+
+        first.set(first_guard);
+        second.set(second_guard);
+
+        assert_eq!(guard.value(), &3);
+
+        assert_drop!(registry, old_id);
+        assert_drop!(registry, first_id);
+        assert_no_drop!(registry, second_id);
+    }
+
+    #[test]
+    fn from_cell() {
+        let registry = DropRegistry::default();
+        let (old_id, old_guard) = registry.new_guard_for(1).by_id();
+        let (first_id, first_guard) = registry.new_guard_for(2).by_id();
+        let (second_id, second_guard) = registry.new_guard_for(3).by_id();
+
+        let cell = Cell::new(old_guard);
+
+        let view = WriteOnlyCellView::from_cell(&cell);
+        view.set(first_guard);
+        view.write(second_guard);
+
+        assert_eq!(cell.into_inner().value(), &3);
+
+        assert_drop!(registry, old_id);
+        assert_no_drop!(registry, first_id);
+        assert_drop!(registry, second_id);
+    }
+
+    #[test]
+    fn from_cell_slice() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (first_id, first_guard) = registry.new_guard_for(3).by_id();
+        let (second_id, second_guard) = registry.new_guard_for(4).by_id();
+
+        {
+            let cell = Cell::from_mut(&mut guards[..]);
+
+            let mut first = WriteOnlyCellView::from_cell_slice(cell);
+            let mut second = WriteOnlyCellView::from_cell_slice(cell);
+
+            first.put_at(0, first_guard);
+            second.write_at(2, second_guard);
+        }
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[3, 1, 4]);
+
+        assert_drop!(registry, old_ids[0]);
+        assert_no_drop!(registry, old_ids[1]);
+        assert_no_drop!(registry, old_ids[2]);
+        assert_no_drop!(registry, first_id);
+        assert_no_drop!(registry, second_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for write-only slice of length 3")]
+    fn from_cell_slice_out_of_bounds() {
+        let cell: &Cell<[u8]> = &Cell::new([0_u8; 3]);
+
+        let mut views = WriteOnlyCellView::from_cell_slice(cell);
+        views.write_at(3, 42);
+    }
+}
@@ -2,14 +2,66 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::marker::PhantomData;
+use core::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::DerefMut,
+    ptr,
+};
 
-use crate::{Put, Write};
+// Only used by `store_atomic`, which is unavailable on targets without atomics.
+#[cfg_attr(not(target_has_atomic = "8"), allow(unused_imports))]
+use core::sync::atomic::{self, Ordering};
+
+use crate::{Put, VolatileWriteOnlyRef, Write, WriteAck, WriteOnlyCellView};
 
 /// A write-only **reference** with **dropping non-volatile** write access.
+///
+/// # Drop semantics
+///
+/// The reference borrows its pointee rather than owning it:
+/// dropping the reference itself never drops the pointee.
+/// Only the old values replaced by the `put` family of methods get dropped.
+///
+/// # Variance
+///
+/// Just like `&'a mut T` the reference is covariant in `'a`, but invariant in `T`.
+/// The invariance stems from the `*mut T` pointer it stores, with the
+/// `PhantomData<&'a mut T>` marker merely making it explicit.
+///
+/// Shortening `'a` is allowed:
+///
+/// ```
+/// use write_only::WriteOnlyRef;
+///
+/// fn shorten<'long: 'short, 'short, T>(reference: WriteOnlyRef<'long, T>) -> WriteOnlyRef<'short, T> {
+///     reference
+/// }
+/// ```
+///
+/// Shortening the lifetimes within `T` is thus rejected:
+///
+/// ```compile_fail
+/// use write_only::WriteOnlyRef;
+///
+/// fn shorten<'a, 'short>(reference: WriteOnlyRef<'a, &'static str>) -> WriteOnlyRef<'a, &'short str> {
+///     reference
+/// }
+/// ```
+///
+/// Just as is extending them:
+///
+/// ```compile_fail
+/// use write_only::WriteOnlyRef;
+///
+/// fn extend<'a, 'short>(reference: WriteOnlyRef<'a, &'short str>) -> WriteOnlyRef<'a, &'static str> {
+///     reference
+/// }
+/// ```
 pub struct WriteOnlyRef<'a, T: 'a> {
     data: *mut T,
-    _phantom: PhantomData<&'a T>,
+    _phantom: PhantomData<&'a mut T>,
 }
 
 impl<'a, T: 'a> WriteOnlyRef<'a, T> {
@@ -36,13 +88,279 @@ impl<'a, T: 'a> WriteOnlyRef<'a, T> {
     /// source lifetime is safe in the context, such as by providing a helper
     /// function taking the lifetime of a host guard for the reference, or by explicit
     /// annotation.
+    #[cfg(feature = "raw")]
     #[inline]
     pub unsafe fn from_ptr(data: *mut T) -> Self {
+        Self::new_unchecked(data)
+    }
+
+    /// Forms a write-only reference from a non-null pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::from_ptr`], with `data` being non-null guaranteed by its type.
+    #[cfg(feature = "raw")]
+    #[inline]
+    pub unsafe fn from_non_null(data: ptr::NonNull<T>) -> Self {
+        Self::new_unchecked(data.as_ptr())
+    }
+
+    /// Forms a write-only reference to the value of an `UnsafeCell`.
+    ///
+    /// Writing through the reference is the kind of interior mutation `UnsafeCell` permits.
+    /// Yet as the cell is merely borrowed immutably, others may be accessing it too.
+    ///
+    /// # Safety
+    ///
+    /// The value of `cell` must not be accessed through any other pointer or reference
+    /// for the duration of lifetime `'a`, and no other write-only reference to it
+    /// may be formed in the meantime.
+    #[cfg(feature = "raw")]
+    #[inline]
+    pub unsafe fn from_unsafe_cell(cell: &'a core::cell::UnsafeCell<T>) -> Self {
+        Self::new_unchecked(cell.get())
+    }
+
+    /// Forms a write-only reference from a pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as for `from_ptr`.
+    #[inline]
+    pub(crate) unsafe fn new_unchecked(data: *mut T) -> Self {
         Self {
             data,
             _phantom: PhantomData,
         }
     }
+
+    /// Forms a write-only reference to the value behind a guard (e.g. a lock guard),
+    /// projecting through its [`DerefMut`] implementation.
+    ///
+    /// The returned reference keeps the guard mutably borrowed, and thus locked.
+    #[inline]
+    pub fn from_guard<G>(guard: &'a mut G) -> Self
+    where
+        G: DerefMut<Target = T> + ?Sized,
+    {
+        Self::from(guard.deref_mut())
+    }
+
+    /// Returns a short-lived write-only reference with **non-dropping volatile** write access to the same memory,
+    /// borrowing rather than consuming `self`.
+    #[inline]
+    pub fn as_volatile(&mut self) -> VolatileWriteOnlyRef<'_, T> {
+        // SAFETY: `self` stays mutably borrowed for as long as the returned reference is alive.
+        unsafe { VolatileWriteOnlyRef::new_unchecked(self.data) }
+    }
+
+    /// Calls `f` with a short-lived write-only reference with **non-dropping volatile**
+    /// write access to the same memory, returning its result.
+    ///
+    /// This scopes volatility to the closure, rather than to the rest of the reference's lifetime:
+    ///
+    /// ```
+    /// use write_only::{prelude::*, Put, Write};
+    ///
+    /// let mut value = 0_u32;
+    ///
+    /// let mut reference = WriteOnlyRef::from(&mut value);
+    /// let written = reference.with_volatile(|volatile| {
+    ///     volatile.write(1);
+    ///     volatile.write(2);
+    ///     2
+    /// });
+    /// reference.put(written + 1);
+    ///
+    /// assert_eq!(value, 3);
+    /// ```
+    #[inline]
+    pub fn with_volatile<R>(&mut self, f: impl FnOnce(&mut VolatileWriteOnlyRef<'_, T>) -> R) -> R {
+        f(&mut self.as_volatile())
+    }
+
+    /// Writes the value the given value without dropping the old value, consuming `self`,
+    /// and returns a [`WriteAck`] proving that the write took place.
+    #[inline]
+    pub fn write_acked(mut self, value: T) -> WriteAck {
+        self.write(value);
+
+        WriteAck::new()
+    }
+
+    /// Returns a shared view with **dropping non-volatile** write access to the value,
+    /// borrowing rather than consuming `self`.
+    ///
+    /// Unlike `self` the view can be written to through a shared reference,
+    /// and thus be handed to multiple borrowers at once.
+    /// See [`WriteOnlyCellView`] for why this is sound.
+    #[inline]
+    pub fn as_write_cell(&mut self) -> &WriteOnlyCellView<T> {
+        // SAFETY: `self` stays mutably borrowed for as long as the returned view is alive.
+        unsafe { WriteOnlyCellView::from_ptr(self.data) }
+    }
+
+    /// Returns a short-lived write-only reference to the `U` located `offset` bytes
+    /// into the referenced value (e.g. one of its fields), borrowing rather than consuming `self`.
+    ///
+    /// The offset of a field can be obtained via [`core::mem::offset_of!`].
+    ///
+    /// # Safety
+    ///
+    /// `offset` must be the offset of an initialized value of type `U`
+    /// contained within the referenced `T`, such as one of its fields,
+    /// and must be suitably aligned for `U` (which e.g. fields of `#[repr(packed)]` structs may not be).
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn project_unchecked<U>(&mut self, offset: usize) -> WriteOnlyRef<'_, U> {
+        debug_assert!(
+            offset.saturating_add(core::mem::size_of::<U>()) <= core::mem::size_of::<T>(),
+            "offset out of bounds"
+        );
+
+        WriteOnlyRef::new_unchecked(self.data.byte_add(offset) as *mut U)
+    }
+
+    /// Converts the write-only reference into a write-only reference to the
+    /// (possibly unaligned) `U` that `f` projects the referenced value's pointer to,
+    /// such as a field of a `#[repr(packed)]` struct.
+    ///
+    /// Writes through the returned reference go through [`ptr::write_unaligned`],
+    /// which makes them sound even for fields that are not sufficiently aligned for `U`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer returned by `f` must point to an initialized value of type `U`
+    /// contained within the referenced `T`, such as one of its fields
+    /// (e.g. as obtained via [`ptr::addr_of_mut!`]).
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn project_packed<U>(
+        self,
+        f: impl FnOnce(*mut T) -> *mut U,
+    ) -> crate::UnalignedWriteOnlyRef<'a, U> {
+        crate::UnalignedWriteOnlyRef::new_unchecked(f(self.data))
+    }
+
+    /// Converts the write-only reference back into a regular mutable reference,
+    /// re-introducing read-access.
+    ///
+    /// # Safety
+    ///
+    /// The referenced value must be initialized, and the caller must have exclusive
+    /// access to it for the duration of lifetime `'a`.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn assume_readable(self) -> &'a mut T {
+        &mut *self.data
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a, T: 'a> WriteOnlyRef<'a, T>
+where
+    T: bytemuck::Pod,
+{
+    /// Converts the write-only reference into a write-only slice of the value's bytes,
+    /// of length `mem::size_of::<T>()`.
+    #[inline]
+    pub fn as_bytes(self) -> crate::WriteOnlySlice<'a, u8> {
+        // SAFETY: any bit-pattern is valid for `T: Pod`.
+        unsafe {
+            crate::WriteOnlySlice::new_unchecked(self.data as *mut u8, core::mem::size_of::<T>())
+        }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> WriteOnlyRef<'a, [T; N]> {
+    /// Copies all elements from `src` into the referenced array, using a memcpy.
+    #[inline]
+    pub fn write_from(&mut self, src: &[T; N])
+    where
+        T: Copy,
+    {
+        // SAFETY: both arrays are valid for `N` elements,
+        // and cannot overlap because mutable references are exclusive.
+        unsafe {
+            (self.data as *mut T).copy_from_nonoverlapping(src.as_ptr(), N);
+        }
+    }
+
+    /// Copies all elements from `src` into the referenced array, using a memcpy.
+    ///
+    /// The length of `src` must be `N`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the length of `src` is not `N`.
+    #[inline]
+    #[track_caller]
+    pub fn write_element_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        let src: &[T; N] = src
+            .try_into()
+            .expect("source slice length does not match array length");
+
+        self.write_from(src);
+    }
+}
+
+impl<'a, T: 'a> WriteOnlyRef<'a, MaybeUninit<T>> {
+    /// Converts the reference to an uninitialized value into a reference to an initialized value.
+    ///
+    /// # Safety
+    ///
+    /// The referenced value must have been initialized (e.g. via [`Write::write`]).
+    /// Calling this when the value is not yet fully initialized is undefined behavior.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn assume_init(self) -> WriteOnlyRef<'a, T> {
+        WriteOnlyRef::new_unchecked(self.data as *mut T)
+    }
+}
+
+macro_rules! impl_store_atomic {
+    ($($width:literal => $int:ty, $atomic:ident;)*) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl<'a> WriteOnlyRef<'a, $int> {
+                #[doc = concat!("Stores `value` into the referenced value atomically, as if via [`core::sync::atomic::", stringify!($atomic), "::store`].")]
+                ///
+                /// # Panics
+                ///
+                /// Panics if `order` is `Acquire` or `AcqRel`,
+                #[doc = concat!("or if the referenced value is not sufficiently aligned for a `", stringify!($atomic), "`.")]
+                #[inline]
+                #[track_caller]
+                pub fn store_atomic(&mut self, value: $int, order: Ordering) {
+                    assert!(
+                        (self.data as usize) % core::mem::align_of::<atomic::$atomic>() == 0,
+                        "attempt to atomically store to an insufficiently aligned address"
+                    );
+
+                    // SAFETY: the atomic type has the same size and bit validity as the integer type,
+                    // and the pointer was checked to be sufficiently aligned.
+                    unsafe { (*(self.data as *const atomic::$atomic)).store(value, order) }
+                }
+            }
+        )*
+    };
+}
+
+impl_store_atomic! {
+    "8" => u8, AtomicU8;
+    "8" => i8, AtomicI8;
+    "16" => u16, AtomicU16;
+    "16" => i16, AtomicI16;
+    "32" => u32, AtomicU32;
+    "32" => i32, AtomicI32;
+    "64" => u64, AtomicU64;
+    "64" => i64, AtomicI64;
+    "ptr" => usize, AtomicUsize;
+    "ptr" => isize, AtomicIsize;
 }
 
 impl<'a, T: 'a> Put<T> for WriteOnlyRef<'a, T> {
@@ -66,7 +384,36 @@ impl<'a, T: 'a> Write<T> for WriteOnlyRef<'a, T> {
 impl<'a, T: 'a> From<&'a mut T> for WriteOnlyRef<'a, T> {
     #[inline]
     fn from(borrow: &'a mut T) -> Self {
-        unsafe { Self::from_ptr(borrow as *mut T) }
+        unsafe { Self::new_unchecked(borrow as *mut T) }
+    }
+}
+
+// SAFETY: a `WriteOnlyRef` is only ever created from a `&'a mut T` (or a pointer the
+// caller vouches to be equally exclusive), so no other reference can observe the pointee
+// while it exists. Sending it to another thread therefore moves the exclusive right to
+// write (and, via `put`, to drop) `T`s there, exactly as sending the `&'a mut T` would.
+unsafe impl<'a, T: 'a> Send for WriteOnlyRef<'a, T> where T: Send {}
+
+// SAFETY: every method accessing the pointee takes `&mut self`, so a shared
+// `&WriteOnlyRef` merely allows comparing and hashing the pointer. Requiring `T: Sync`
+// is thus stricter than necessary, but keeps the bounds in line with `&'a mut T`.
+unsafe impl<'a, T: 'a> Sync for WriteOnlyRef<'a, T> where T: Sync {}
+
+/// Compares by identity (i.e. same pointer), not by value.
+impl<'a, T: 'a> PartialEq for WriteOnlyRef<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data)
+    }
+}
+
+impl<'a, T: 'a> Eq for WriteOnlyRef<'a, T> {}
+
+/// Hashes by identity (i.e. by pointer), not by value.
+impl<'a, T: 'a> Hash for WriteOnlyRef<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
     }
 }
 
@@ -77,6 +424,8 @@ mod tests {
     use droptest::prelude::*;
 
     #[test]
+    #[cfg(feature = "raw")]
+    #[allow(clippy::drop_non_drop)]
     fn from_ptr() {
         let registry = DropRegistry::default();
         let (id, mut guard) = registry.new_guard_for(1).by_id();
@@ -89,6 +438,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::drop_non_drop)]
     fn from() {
         let registry = DropRegistry::default();
         let (id, mut guard) = registry.new_guard_for(1).by_id();
@@ -139,4 +489,300 @@ mod tests {
         assert_no_drop!(registry, old_id);
         assert_drop!(registry, new_id);
     }
+
+    #[test]
+    fn put_with() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+
+        let mut reference = WriteOnlyRef::from(&mut guard);
+        reference.put_with(|| registry.new_guard_for(2));
+
+        assert_eq!(guard.value(), &2);
+
+        assert_drop!(registry, old_id);
+        assert_drop_stats!(registry, { created: 2, dropped: 1 });
+    }
+
+    #[test]
+    fn write_acked() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let reference = WriteOnlyRef::from(&mut guard);
+        let _ack: crate::WriteAck = reference.write_acked(new_guard);
+
+        assert_eq!(guard.value(), &2);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn put_if() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (skipped_id, skipped_guard) = registry.new_guard_for(2).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut reference = WriteOnlyRef::from(&mut guard);
+        reference.put_if(false, skipped_guard);
+
+        assert_no_drop!(registry, old_id);
+        assert_drop!(registry, skipped_id);
+
+        reference.put_if(true, new_guard);
+
+        assert_eq!(guard.value(), &3);
+
+        assert_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn write_if() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (skipped_id, skipped_guard) = registry.new_guard_for(2).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut reference = WriteOnlyRef::from(&mut guard);
+        reference.write_if(false, skipped_guard);
+
+        assert_eq!(guard.value(), &1);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, skipped_id);
+
+        let mut reference = WriteOnlyRef::from(&mut guard);
+        reference.write_if(true, new_guard);
+
+        assert_eq!(guard.value(), &3);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::HashSet;
+
+        let mut values = [0, 1];
+        let ptr = values.as_mut_ptr();
+
+        let (first, second) = unsafe {
+            (
+                WriteOnlyRef::new_unchecked(ptr),
+                WriteOnlyRef::new_unchecked(ptr.add(1)),
+            )
+        };
+        let first_again = unsafe { WriteOnlyRef::new_unchecked(ptr) };
+
+        assert!(first == first_again);
+        assert!(first != second);
+
+        let set: HashSet<_> = [first, second, first_again].into_iter().collect();
+
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "raw")]
+    fn from_non_null() {
+        let mut value = 0;
+
+        let mut reference = unsafe { WriteOnlyRef::from_non_null(ptr::NonNull::from(&mut value)) };
+        reference.write(42);
+
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn assume_init() {
+        let mut value: MaybeUninit<i32> = MaybeUninit::uninit();
+
+        let mut reference = WriteOnlyRef::from(&mut value);
+        reference.write(MaybeUninit::new(42));
+
+        let mut reference = unsafe { reference.assume_init() };
+        reference.put(43);
+
+        assert_eq!(unsafe { value.assume_init() }, 43);
+    }
+
+    #[test]
+    #[cfg(feature = "raw")]
+    fn from_unsafe_cell() {
+        let registry = DropRegistry::default();
+        let (old_id, guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let cell = std::cell::UnsafeCell::new(guard);
+
+        let mut reference = unsafe { WriteOnlyRef::from_unsafe_cell(&cell) };
+        reference.put(new_guard);
+
+        assert_eq!(cell.into_inner().value(), &2);
+
+        assert_drop!(registry, old_id);
+        assert_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn with_volatile() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let mut reference = WriteOnlyRef::from(&mut guard);
+        let result = reference.with_volatile(|volatile| {
+            volatile.write(new_guard);
+            42
+        });
+
+        assert_eq!(result, 42);
+        assert_eq!(guard.value(), &2);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn as_volatile() {
+        let mut value = 0;
+
+        let mut reference = WriteOnlyRef::from(&mut value);
+        reference.as_volatile().write(1);
+        reference.put(2);
+
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    fn store_atomic() {
+        let mut value: u32 = 0;
+
+        let mut reference = WriteOnlyRef::from(&mut value);
+        reference.store_atomic(42, Ordering::SeqCst);
+
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn store_atomic_invalid_ordering() {
+        let mut value: u32 = 0;
+
+        let mut reference = WriteOnlyRef::from(&mut value);
+        reference.store_atomic(42, Ordering::Acquire);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn assume_readable() {
+        let mut value = 0;
+
+        let mut reference = WriteOnlyRef::from(&mut value);
+        reference.write(42);
+
+        let readable = unsafe { reference.assume_readable() };
+
+        assert_eq!(*readable, 42);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn as_bytes() {
+        let mut value: u32 = 0;
+
+        let reference = WriteOnlyRef::from(&mut value);
+        reference
+            .as_bytes()
+            .write_copying_from_slice_at(&42u32.to_ne_bytes(), 0);
+
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    fn drop_does_not_drop_pointee() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let mut reference = WriteOnlyRef::from(&mut guard);
+        reference.put(new_guard);
+
+        std::mem::drop(reference);
+
+        assert_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+
+        std::mem::drop(guard);
+
+        assert_drop!(registry, new_id);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn project_unchecked() {
+        struct Pair {
+            first: u8,
+            second: u32,
+        }
+
+        let mut pair = Pair {
+            first: 1,
+            second: 2,
+        };
+
+        let mut reference = WriteOnlyRef::from(&mut pair);
+        unsafe { reference.project_unchecked::<u32>(core::mem::offset_of!(Pair, second)) }.put(42);
+
+        assert_eq!(pair.first, 1);
+        assert_eq!(pair.second, 42);
+    }
+
+    #[test]
+    fn from_guard() {
+        let mutex = std::sync::Mutex::new(1);
+
+        let mut guard = mutex.lock().unwrap();
+        WriteOnlyRef::from_guard(&mut guard).put(42);
+        std::mem::drop(guard);
+
+        assert_eq!(*mutex.lock().unwrap(), 42);
+    }
+
+    #[test]
+    fn write_from() {
+        let mut block = [0_u32; 4];
+
+        let mut reference = WriteOnlyRef::from(&mut block);
+        reference.write_from(&[1, 2, 3, 4]);
+
+        assert_eq!(block, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn write_element_from_slice() {
+        let mut block = [0_u32; 4];
+        let values = vec![1, 2, 3, 4];
+
+        let mut reference = WriteOnlyRef::from(&mut block);
+        reference.write_element_from_slice(&values);
+
+        assert_eq!(block, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "source slice length does not match array length")]
+    fn write_element_from_slice_wrong_length() {
+        let mut block = [0_u32; 4];
+
+        let mut reference = WriteOnlyRef::from(&mut block);
+        reference.write_element_from_slice(&[1, 2, 3]);
+    }
 }
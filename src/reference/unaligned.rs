@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ptr,
+};
+
+use crate::{Put, Write};
+
+/// A write-only **reference** with **dropping non-volatile** write access
+/// to a **possibly unaligned** value, such as a field of a `#[repr(packed)]` struct,
+/// obtained via [`WriteOnlyRef::project_packed`](crate::WriteOnlyRef::project_packed).
+///
+/// All writes go through [`ptr::write_unaligned`], making it sound
+/// to write to values that are not sufficiently aligned for `T`.
+///
+/// ```
+/// # #[cfg(any(feature = "raw", not(feature = "safe")))]
+/// # fn main() {
+/// use write_only::prelude::*;
+///
+/// #[repr(C, packed)]
+/// struct Header {
+///     tag: u8,
+///     len: u32,
+/// }
+///
+/// let mut header = Header { tag: 1, len: 0 };
+///
+/// let reference = WriteOnlyRef::from(&mut header);
+///
+/// // SAFETY: the projected pointer points to the `len` field of `header`.
+/// let mut len = unsafe { reference.project_packed(|ptr| core::ptr::addr_of_mut!((*ptr).len)) };
+/// len.put(42);
+///
+/// assert_eq!({ header.len }, 42);
+/// # }
+/// # #[cfg(not(any(feature = "raw", not(feature = "safe"))))]
+/// # fn main() {}
+/// ```
+pub struct UnalignedWriteOnlyRef<'a, T: 'a> {
+    data: *mut T,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> UnalignedWriteOnlyRef<'a, T> {
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub(crate) unsafe fn new_unchecked(data: *mut T) -> Self {
+        Self {
+            data,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a> Put<T> for UnalignedWriteOnlyRef<'a, T> {
+    #[inline]
+    fn put(&mut self, value: T) {
+        // SAFETY: `self.data` points to an initialized (possibly unaligned) `T`
+        // by definition. The old value gets moved out before being dropped,
+        // so it never gets dropped in place through an unaligned pointer.
+        let old_value = unsafe {
+            let old_value = self.data.read_unaligned();
+            self.data.write_unaligned(value);
+            old_value
+        };
+
+        drop(old_value);
+    }
+}
+
+impl<'a, T: 'a> Write<T> for UnalignedWriteOnlyRef<'a, T> {
+    #[inline]
+    fn write(&mut self, value: T) {
+        // SAFETY: `self.data` is valid for (possibly unaligned) writes by definition.
+        unsafe {
+            self.data.write_unaligned(value);
+        }
+    }
+}
+
+// SAFETY: an `UnalignedWriteOnlyRef` points into a place exclusively borrowed for `'a`
+// (e.g. a field of a `#[repr(packed)]` struct), so sending it to another thread moves the
+// exclusive right to write (and, via `put`, to drop) `T`s there, like a `&'a mut T` would.
+unsafe impl<'a, T: 'a> Send for UnalignedWriteOnlyRef<'a, T> where T: Send {}
+
+// SAFETY: all unaligned writes take `&mut self`, so a shared `&UnalignedWriteOnlyRef`
+// merely allows comparing and hashing the pointer.
+unsafe impl<'a, T: 'a> Sync for UnalignedWriteOnlyRef<'a, T> where T: Sync {}
+
+/// Compares by identity (i.e. same pointer), not by value.
+impl<'a, T: 'a> PartialEq for UnalignedWriteOnlyRef<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data)
+    }
+}
+
+impl<'a, T: 'a> Eq for UnalignedWriteOnlyRef<'a, T> {}
+
+/// Hashes by identity (i.e. by pointer), not by value.
+impl<'a, T: 'a> Hash for UnalignedWriteOnlyRef<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+#[cfg(all(test, any(feature = "raw", not(feature = "safe"))))]
+mod tests {
+    use droptest::prelude::*;
+
+    use crate::WriteOnlyRef;
+
+    use super::*;
+
+    #[repr(C, packed)]
+    struct Packed<T> {
+        tag: u8,
+        value: T,
+    }
+
+    #[test]
+    fn put() {
+        let registry = DropRegistry::default();
+        let (old_id, old_guard) = registry.new_guard_for(1).by_id();
+        let mut packed = Packed {
+            tag: 0,
+            value: old_guard,
+        };
+
+        {
+            let reference = WriteOnlyRef::from(&mut packed);
+            let mut value =
+                unsafe { reference.project_packed(|ptr| ptr::addr_of_mut!((*ptr).value)) };
+            value.put(registry.new_guard_for(2));
+        }
+
+        assert_drop!(registry, old_id);
+        assert_drop_stats!(registry, { created: 2, dropped: 1 });
+
+        assert_eq!(packed.tag, 0);
+    }
+
+    #[test]
+    fn write() {
+        let registry = DropRegistry::default();
+        let (old_id, old_guard) = registry.new_guard_for(1).by_id();
+        let mut packed = Packed {
+            tag: 0,
+            value: old_guard,
+        };
+
+        {
+            let reference = WriteOnlyRef::from(&mut packed);
+            let mut value =
+                unsafe { reference.project_packed(|ptr| ptr::addr_of_mut!((*ptr).value)) };
+            value.write(registry.new_guard_for(2));
+        }
+
+        assert_no_drop!(registry, old_id);
+        assert_drop_stats!(registry, { created: 2, dropped: 0 });
+    }
+
+    #[test]
+    fn write_unaligned_u32() {
+        let mut packed = Packed {
+            tag: 7,
+            value: 0_u32,
+        };
+
+        {
+            let reference = WriteOnlyRef::from(&mut packed);
+            let mut value =
+                unsafe { reference.project_packed(|ptr| ptr::addr_of_mut!((*ptr).value)) };
+            value.write(0xdead_beef);
+        }
+
+        assert_eq!(({ packed.tag }, { packed.value }), (7, 0xdead_beef));
+    }
+}
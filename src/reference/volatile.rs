@@ -2,14 +2,61 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::marker::PhantomData;
+use core::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::DerefMut,
+    ptr,
+};
 
-use crate::Write;
+use crate::{VolatileWriteOnlySlice, Write, WriteAck, WriteOnlyRef};
 
 /// A write-only **reference** with **non-dropping volatile** write access.
+///
+/// # Drop semantics
+///
+/// The reference borrows its pointee rather than owning it:
+/// dropping the reference itself never drops the pointee.
+/// Neither are the old values replaced by its writes ever dropped.
+///
+/// # Variance
+///
+/// Just like `&'a mut T` the reference is covariant in `'a`, but invariant in `T`.
+///
+/// Shortening `'a` is allowed:
+///
+/// ```
+/// use write_only::VolatileWriteOnlyRef;
+///
+/// fn shorten<'long: 'short, 'short, T>(reference: VolatileWriteOnlyRef<'long, T>) -> VolatileWriteOnlyRef<'short, T> {
+///     reference
+/// }
+/// ```
+///
+/// Shortening the lifetimes within `T` is rejected,
+/// as otherwise a shorter-lived value could be written into longer-lived storage:
+///
+/// ```compile_fail
+/// use write_only::VolatileWriteOnlyRef;
+///
+/// fn shorten<'a, 'short>(reference: VolatileWriteOnlyRef<'a, &'static str>) -> VolatileWriteOnlyRef<'a, &'short str> {
+///     reference
+/// }
+/// ```
+///
+/// Just as is extending them:
+///
+/// ```compile_fail
+/// use write_only::VolatileWriteOnlyRef;
+///
+/// fn extend<'a, 'short>(reference: VolatileWriteOnlyRef<'a, &'short str>) -> VolatileWriteOnlyRef<'a, &'static str> {
+///     reference
+/// }
+/// ```
 pub struct VolatileWriteOnlyRef<'a, T: 'a> {
     data: *mut T,
-    _phantom: PhantomData<&'a T>,
+    _phantom: PhantomData<&'a mut T>,
 }
 
 impl<'a, T: 'a> VolatileWriteOnlyRef<'a, T> {
@@ -38,17 +85,161 @@ impl<'a, T: 'a> VolatileWriteOnlyRef<'a, T> {
     /// source lifetime is safe in the context, such as by providing a helper
     /// function taking the lifetime of a host guard for the reference, or by explicit
     /// annotation.
+    #[cfg(feature = "raw")]
     #[inline]
     pub unsafe fn from_ptr(data: *mut T) -> Self {
+        Self::new_unchecked(data)
+    }
+
+    /// Forms a write-only reference from a non-null pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::from_ptr`], with `data` being non-null guaranteed by its type.
+    #[cfg(feature = "raw")]
+    #[inline]
+    pub unsafe fn from_non_null(data: ptr::NonNull<T>) -> Self {
+        Self::new_unchecked(data.as_ptr())
+    }
+
+    /// Forms a write-only reference from a pointer.
+    ///
+    /// # Safety
+    ///
+    /// Same as for `from_ptr`.
+    #[inline]
+    pub(crate) unsafe fn new_unchecked(data: *mut T) -> Self {
         Self {
             data,
             _phantom: PhantomData,
         }
     }
+
+    /// Forms a write-only reference to the value behind a guard (e.g. a lock guard),
+    /// projecting through its [`DerefMut`] implementation.
+    ///
+    /// The returned reference keeps the guard mutably borrowed, and thus locked.
+    #[inline]
+    pub fn from_guard<G>(guard: &'a mut G) -> Self
+    where
+        G: DerefMut<Target = T> + ?Sized,
+    {
+        Self::from(guard.deref_mut())
+    }
+
+    /// Returns a short-lived write-only reference with **dropping non-volatile** write access to the same memory,
+    /// borrowing rather than consuming `self`.
+    #[inline]
+    pub fn as_non_volatile(&mut self) -> WriteOnlyRef<'_, T> {
+        // SAFETY: `self` stays mutably borrowed for as long as the returned reference is alive.
+        unsafe { WriteOnlyRef::new_unchecked(self.data) }
+    }
+
+    /// Writes the value the given value without dropping the old value, consuming `self`,
+    /// and returns a [`WriteAck`] proving that the write took place.
+    #[inline]
+    pub fn write_acked(mut self, value: T) -> WriteAck {
+        self.write(value);
+
+        WriteAck::new()
+    }
+
+    /// Returns a short-lived write-only reference to the `U` located `offset` bytes
+    /// into the referenced value (e.g. one of its fields), borrowing rather than consuming `self`.
+    ///
+    /// The offset of a field can be obtained via [`core::mem::offset_of!`].
+    ///
+    /// # Safety
+    ///
+    /// `offset` must be the offset of an initialized value of type `U`
+    /// contained within the referenced `T`, such as one of its fields,
+    /// and must be suitably aligned for `U` (which e.g. fields of `#[repr(packed)]` structs may not be).
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn project_unchecked<U>(&mut self, offset: usize) -> VolatileWriteOnlyRef<'_, U> {
+        debug_assert!(
+            offset.saturating_add(core::mem::size_of::<U>()) <= core::mem::size_of::<T>(),
+            "offset out of bounds"
+        );
+
+        VolatileWriteOnlyRef::new_unchecked(self.data.byte_add(offset) as *mut U)
+    }
+
+    /// Converts the write-only reference back into a regular mutable reference,
+    /// re-introducing read-access.
+    ///
+    /// # Safety
+    ///
+    /// The referenced value must be initialized, and the caller must have exclusive
+    /// access to it for the duration of lifetime `'a`.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn assume_readable(self) -> &'a mut T {
+        &mut *self.data
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a, T: 'a> VolatileWriteOnlyRef<'a, T>
+where
+    T: bytemuck::Pod,
+{
+    /// Converts the write-only reference into a write-only slice of the value's bytes,
+    /// of length `mem::size_of::<T>()`.
+    #[inline]
+    pub fn as_bytes(self) -> VolatileWriteOnlySlice<'a, u8> {
+        // SAFETY: any bit-pattern is valid for `T: Pod`.
+        unsafe {
+            VolatileWriteOnlySlice::new_unchecked(self.data as *mut u8, core::mem::size_of::<T>())
+        }
+    }
 }
 
-impl<'a, T: 'a> Write<T> for VolatileWriteOnlyRef<'a, T> {
+impl<'a, T: 'a, const N: usize> VolatileWriteOnlyRef<'a, [T; N]> {
+    /// Performs a volatile write of the array's element at `index` with the given value,
+    /// without reading or dropping the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn write_element(&mut self, index: usize, value: T) {
+        crate::slice::assert_index_in_bounds(index, N);
+
+        // SAFETY: `index` was checked to be within the array's bounds.
+        unsafe {
+            (self.data as *mut T).add(index).write_volatile(value);
+        }
+    }
+
+    /// Returns a short-lived write-only slice with **non-dropping volatile** write access
+    /// to the array's elements, borrowing rather than consuming `self`.
     #[inline]
+    pub fn as_slice(&mut self) -> VolatileWriteOnlySlice<'_, T> {
+        // SAFETY: `self` stays mutably borrowed for as long as the returned slice is alive.
+        unsafe { VolatileWriteOnlySlice::new_unchecked(self.data as *mut T, N) }
+    }
+}
+
+impl<'a, T: 'a> VolatileWriteOnlyRef<'a, MaybeUninit<T>> {
+    /// Converts the reference to an uninitialized value into a reference to an initialized value.
+    ///
+    /// # Safety
+    ///
+    /// The referenced value must have been initialized (e.g. via [`Write::write`]).
+    /// Calling this when the value is not yet fully initialized is undefined behavior.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn assume_init(self) -> VolatileWriteOnlyRef<'a, T> {
+        VolatileWriteOnlyRef::new_unchecked(self.data as *mut T)
+    }
+}
+
+impl<'a, T: 'a> Write<T> for VolatileWriteOnlyRef<'a, T> {
+    #[cfg_attr(not(feature = "debug-no-inline"), inline)]
+    #[cfg_attr(feature = "debug-no-inline", inline(never))]
     fn write(&mut self, guard: T) {
         unsafe {
             self.data.write_volatile(guard);
@@ -59,7 +250,35 @@ impl<'a, T: 'a> Write<T> for VolatileWriteOnlyRef<'a, T> {
 impl<'a, T: 'a> From<&'a mut T> for VolatileWriteOnlyRef<'a, T> {
     #[inline]
     fn from(borrow: &'a mut T) -> Self {
-        unsafe { Self::from_ptr(borrow as *mut T) }
+        unsafe { Self::new_unchecked(borrow as *mut T) }
+    }
+}
+
+// SAFETY: a `VolatileWriteOnlyRef` is only ever created from a `&'a mut T` (or a pointer
+// the caller vouches to be equally exclusive). Volatile writes are not atomic, so it is this
+// exclusivity (which moving the reference to another thread preserves) that rules out races.
+// Values written from the receiving thread end up owned by the sender's memory, hence `T: Send`.
+unsafe impl<'a, T: 'a> Send for VolatileWriteOnlyRef<'a, T> where T: Send {}
+
+// SAFETY: `write` takes `&mut self`, so a shared `&VolatileWriteOnlyRef` merely allows
+// comparing and hashing the pointer, and can thus never issue a volatile write.
+unsafe impl<'a, T: 'a> Sync for VolatileWriteOnlyRef<'a, T> where T: Sync {}
+
+/// Compares by identity (i.e. same pointer), not by value.
+impl<'a, T: 'a> PartialEq for VolatileWriteOnlyRef<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data)
+    }
+}
+
+impl<'a, T: 'a> Eq for VolatileWriteOnlyRef<'a, T> {}
+
+/// Hashes by identity (i.e. by pointer), not by value.
+impl<'a, T: 'a> Hash for VolatileWriteOnlyRef<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
     }
 }
 
@@ -67,9 +286,13 @@ impl<'a, T: 'a> From<&'a mut T> for VolatileWriteOnlyRef<'a, T> {
 mod tests {
     use super::*;
 
+    use crate::Put;
+
     use droptest::prelude::*;
 
     #[test]
+    #[cfg(feature = "raw")]
+    #[allow(clippy::drop_non_drop)]
     fn from_ptr() {
         let registry = DropRegistry::default();
         let (id, mut guard) = registry.new_guard_for(1).by_id();
@@ -82,6 +305,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::drop_non_drop)]
     fn from() {
         let registry = DropRegistry::default();
         let (id, mut guard) = registry.new_guard_for(1).by_id();
@@ -113,4 +337,231 @@ mod tests {
         assert_no_drop!(registry, old_id);
         assert_drop!(registry, new_id);
     }
+
+    #[test]
+    fn write_with() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut guard);
+        reference.write_with(|| registry.new_guard_for(2));
+
+        assert_eq!(guard.value(), &2);
+
+        assert_no_drop!(registry, old_id);
+        assert_drop_stats!(registry, { created: 2, dropped: 0 });
+    }
+
+    #[test]
+    fn write_acked() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let reference = VolatileWriteOnlyRef::from(&mut guard);
+        let _ack: crate::WriteAck = reference.write_acked(new_guard);
+
+        assert_eq!(guard.value(), &2);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn write_if() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (skipped_id, skipped_guard) = registry.new_guard_for(2).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut guard);
+        reference.write_if(false, skipped_guard);
+
+        assert_eq!(guard.value(), &1);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, skipped_id);
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut guard);
+        reference.write_if(true, new_guard);
+
+        assert_eq!(guard.value(), &3);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::HashSet;
+
+        let mut values = [0, 1];
+        let ptr = values.as_mut_ptr();
+
+        let (first, second) = unsafe {
+            (
+                VolatileWriteOnlyRef::new_unchecked(ptr),
+                VolatileWriteOnlyRef::new_unchecked(ptr.add(1)),
+            )
+        };
+        let first_again = unsafe { VolatileWriteOnlyRef::new_unchecked(ptr) };
+
+        assert!(first == first_again);
+        assert!(first != second);
+
+        let set: HashSet<_> = [first, second, first_again].into_iter().collect();
+
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "raw")]
+    fn from_non_null() {
+        let mut value = 0;
+
+        let mut reference =
+            unsafe { VolatileWriteOnlyRef::from_non_null(ptr::NonNull::from(&mut value)) };
+        reference.write(42);
+
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn assume_init() {
+        let mut value: MaybeUninit<i32> = MaybeUninit::uninit();
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut value);
+        reference.write(MaybeUninit::new(42));
+
+        let reference = unsafe { reference.assume_init() };
+
+        std::mem::drop(reference);
+
+        assert_eq!(unsafe { value.assume_init() }, 42);
+    }
+
+    #[test]
+    fn as_non_volatile() {
+        let mut value = 0;
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut value);
+        reference.as_non_volatile().put(1);
+
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn assume_readable() {
+        let mut value = 0;
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut value);
+        reference.write(42);
+
+        let readable = unsafe { reference.assume_readable() };
+
+        assert_eq!(*readable, 42);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn as_bytes() {
+        let mut value: u32 = 0;
+
+        let reference = VolatileWriteOnlyRef::from(&mut value);
+        reference
+            .as_bytes()
+            .write_copying_from_slice_at(&42u32.to_ne_bytes(), 0);
+
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    fn drop_does_not_drop_pointee() {
+        let registry = DropRegistry::default();
+        let (old_id, mut guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut guard);
+        reference.write(new_guard);
+
+        std::mem::drop(reference);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+
+        std::mem::drop(guard);
+
+        assert_no_drop!(registry, old_id);
+        assert_drop!(registry, new_id);
+    }
+
+    #[test]
+    fn write_element() {
+        let mut registers = [0_u32; 4];
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut registers);
+        reference.write_element(1, 10);
+        reference.write_element(3, 30);
+
+        assert_eq!(registers, [0, 10, 0, 30]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for write-only slice of length 4")]
+    fn write_element_out_of_bounds() {
+        let mut registers = [0_u32; 4];
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut registers);
+        reference.write_element(4, 40);
+    }
+
+    #[test]
+    fn as_slice() {
+        use crate::WriteAt;
+
+        let mut registers = [0_u32; 4];
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut registers);
+        let mut slice = reference.as_slice();
+        slice.write_at(2, 20);
+
+        assert_eq!(slice.len(), 4);
+        assert_eq!(registers, [0, 0, 20, 0]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn project_unchecked() {
+        struct Pair {
+            first: u8,
+            second: u32,
+        }
+
+        let mut pair = Pair {
+            first: 1,
+            second: 2,
+        };
+
+        let mut reference = VolatileWriteOnlyRef::from(&mut pair);
+        unsafe { reference.project_unchecked::<u32>(core::mem::offset_of!(Pair, second)) }
+            .write(42);
+
+        assert_eq!(pair.first, 1);
+        assert_eq!(pair.second, 42);
+    }
+
+    #[test]
+    fn from_guard() {
+        let mutex = std::sync::Mutex::new(1);
+
+        let mut guard = mutex.lock().unwrap();
+        VolatileWriteOnlyRef::from_guard(&mut guard).write(42);
+        std::mem::drop(guard);
+
+        assert_eq!(*mutex.lock().unwrap(), 42);
+    }
 }
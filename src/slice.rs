@@ -4,11 +4,29 @@
 
 //! Slices that only provide write-access, no read.
 
+use core::{
+    mem,
+    ops::{Bound, Range, RangeBounds},
+    ptr,
+};
+
+mod grid;
+mod non_empty;
 mod non_volatile;
+#[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+mod overlap;
+mod slice_ref;
+mod unchecked;
+mod uninit;
 mod volatile;
 
-pub use non_volatile::WriteOnlySlice;
-pub use volatile::VolatileWriteOnlySlice;
+pub use grid::VolatileWriteOnlyGrid;
+pub use non_empty::WriteOnlyNonEmptySlice;
+pub use non_volatile::{IntoIter, WriteOnlySlice};
+pub use slice_ref::WriteOnlySliceRef;
+pub use unchecked::UncheckedWriter;
+pub use uninit::UninitWriteOnlySlice;
+pub use volatile::{VolatileIntoIter, VolatileWriteOnlySlice};
 
 /// A trait for objects which provide **dropping indexed** write access to their values.
 pub trait PutAt<T> {
@@ -26,10 +44,16 @@ pub trait PutAt<T> {
     /// # Safety
     ///
     /// Calling this method with an out-of-bounds index is undefined behavior.
+    /// Implementations are expected to catch this via a `debug_assert!` in debug builds.
     unsafe fn put_at_unchecked(&mut self, index: usize, value: T);
 }
 
 /// A trait for objects which provide **dropping indexed** write access to their values from a slice.
+///
+/// The inherent methods of the same names on the crate's slice types
+/// additionally accept any source that can be viewed as a slice, such as an array or a `Vec<T>`.
+///
+/// An empty source writes nothing, though `offset` still gets checked for being in bounds.
 pub trait PutFromSliceAt<T>: PutAt<T> {
     /// Clones the elements from `src` into self, starting at `offset`, dropping the old values.
     ///
@@ -37,10 +61,33 @@ pub trait PutFromSliceAt<T>: PutAt<T> {
     ///
     /// # Panics
     ///
-    /// This function will panic if the length of `src` is greater than `self.len - offset`.
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    ///
+    /// If cloning an element of `src`, or dropping an old value of `self` panics,
+    /// then every slot of `self` is still left holding a valid value:
+    /// slots before the failing one hold their new values, slots after it their old values.
+    /// A slot whose old value panicked on drop holds its new value.
     fn put_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
     where
         T: Clone;
+
+    /// Copies all elements from `src` into self, starting at `offset`, dropping the old values, using a memcpy.
+    ///
+    /// Since `Copy` types cannot implement `Drop`, dropping the old values is a no-op,
+    /// making this exactly as performant as [`WriteFromSliceAt::write_copying_from_slice_at`].
+    ///
+    /// The length of `src` must be less than `self.len - offset`.
+    ///
+    /// If `T` does not implement `Copy`, use [`PutFromSliceAt::put_cloning_from_slice_at`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    fn put_copying_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Copy;
 }
 
 /// A trait for objects which provide **non-dropping indexed** write access to their values.
@@ -59,10 +106,16 @@ pub trait WriteAt<T> {
     /// # Safety
     ///
     /// Calling this method with an out-of-bounds index is undefined behavior.
+    /// Implementations are expected to catch this via a `debug_assert!` in debug builds.
     unsafe fn write_at_unchecked(&mut self, index: usize, value: T);
 }
 
 /// A trait for objects which provide **non-dropping indexed** write access to their values from a slice.
+///
+/// The inherent methods of the same names on the crate's slice types
+/// additionally accept any source that can be viewed as a slice, such as an array or a `Vec<T>`.
+///
+/// An empty source writes nothing, though `offset` still gets checked for being in bounds.
 pub trait WriteFromSliceAt<T>: WriteAt<T> {
     /// Copies the elements from `src` into `self`.
     ///
@@ -73,21 +126,315 @@ pub trait WriteFromSliceAt<T>: WriteAt<T> {
     ///
     /// # Panics
     ///
-    /// This function will panic if the length of `src` is greater than `self.len - offset`.
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
     fn write_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
     where
         T: Clone;
 
     /// Copies all elements from `src` into `self`, using a memcpy.
     ///
+    /// Volatile implementors must not use a memcpy, but perform one volatile write per element instead.
+    ///
     /// The length of `src` must be less than `self.len - offset`.
     ///
     /// If `T` does not implement `Copy`, use [`WriteFromSliceAt::write_cloning_from_slice_at`].
     ///
     /// # Panics
     ///
-    /// This function will panic if the length of `src` is greater than `self.len - offset`.
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
     fn write_copying_from_slice_at(&mut self, src: &[T], offset: usize)
     where
         T: Copy;
+
+    /// Copies all elements from `src` into `self`, starting at `written_so_far`, using a memcpy.
+    ///
+    /// Returns the position right past the last copied element, allowing for chained appends:
+    ///
+    /// ```
+    /// use write_only::{prelude::*, WriteFromSliceAt};
+    ///
+    /// let mut values: Vec<u8> = vec![0; 5];
+    ///
+    /// let mut write_only = WriteOnlySlice::from(&mut values[..]);
+    /// let pos = write_only.write_suffix_from_slice(0, &[1, 2]);
+    /// let pos = write_only.write_suffix_from_slice(pos, &[3, 4, 5]);
+    ///
+    /// assert_eq!(pos, 5);
+    /// assert_eq!(values, &[1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `written_so_far` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - written_so_far`.
+    #[inline]
+    #[track_caller]
+    fn write_suffix_from_slice(&mut self, written_so_far: usize, src: &[T]) -> usize
+    where
+        T: Copy,
+    {
+        self.write_copying_from_slice_at(src, written_so_far);
+
+        written_so_far + src.len()
+    }
+}
+
+impl<T> PutAt<T> for [T] {
+    #[inline]
+    #[track_caller]
+    fn put_at(&mut self, index: usize, value: T) {
+        assert_index_in_bounds(index, self.len());
+
+        // SAFETY: `index` was checked to be within bounds.
+        unsafe { self.put_at_unchecked(index, value) };
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn put_at_unchecked(&mut self, index: usize, value: T) {
+        debug_assert!(index < self.len(), "index out of bounds");
+
+        *self.get_unchecked_mut(index) = value;
+    }
+}
+
+impl<T> WriteAt<T> for [T] {
+    #[inline]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        assert_index_in_bounds(index, self.len());
+
+        // SAFETY: `index` was checked to be within bounds.
+        unsafe { self.write_at_unchecked(index, value) };
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        debug_assert!(index < self.len(), "index out of bounds");
+
+        ptr::write(self.get_unchecked_mut(index), value);
+    }
+}
+
+impl<T, const N: usize> PutAt<T> for [T; N] {
+    #[inline]
+    #[track_caller]
+    fn put_at(&mut self, index: usize, value: T) {
+        self.as_mut_slice().put_at(index, value);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn put_at_unchecked(&mut self, index: usize, value: T) {
+        self.as_mut_slice().put_at_unchecked(index, value);
+    }
+}
+
+impl<T, const N: usize> WriteAt<T> for [T; N] {
+    #[inline]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        self.as_mut_slice().write_at(index, value);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        self.as_mut_slice().write_at_unchecked(index, value);
+    }
+}
+
+/// Resolves `range` against a slice of length `len`.
+///
+/// # Panics
+///
+/// Panics if `range` is out of bounds, or if its start is greater than its end.
+#[inline]
+#[track_caller]
+fn resolve_range<R>(range: R, len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .expect("attempted to index slice up to maximum usize"),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "slice index starts at {start} but ends at {end}"
+    );
+    if end > len {
+        panic_oob(end - 1, len);
+    }
+
+    start..end
+}
+
+/// Panics, reporting `index` to be out of bounds for a write-only slice of length `len`.
+///
+/// Shared by all of the crate's bounds checks, for consistent panic messages.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_oob(index: usize, len: usize) -> ! {
+    panic!("index {index} out of bounds for write-only slice of length {len}")
+}
+
+/// Asserts that `index` is in bounds for a slice of length `len`.
+#[inline]
+#[track_caller]
+pub(crate) fn assert_index_in_bounds(index: usize, len: usize) {
+    if index >= len {
+        panic_oob(index, len);
+    }
+}
+
+/// Asserts that `position` (e.g. a split point) is at most `len`.
+#[inline]
+#[track_caller]
+fn assert_position_in_bounds(position: usize, len: usize) {
+    if position > len {
+        panic_oob(position, len);
+    }
+}
+
+/// Asserts that writing `count` elements into a slice of length `len`,
+/// starting at `offset`, stays within bounds.
+///
+/// Reports the last index the write would have touched.
+#[inline]
+#[track_caller]
+fn assert_range_in_bounds(offset: usize, count: usize, len: usize) {
+    if offset > len || count > len - offset {
+        panic_oob(offset.saturating_add(count.saturating_sub(1)), len);
+    }
+}
+
+/// Debug-asserts that `index` is in bounds for a slice of length `len`,
+/// and that the byte offset of the element at `index` fits into an `isize`,
+/// as required by `pointer::add`.
+///
+/// The latter follows from the former for slices upholding their constructors' safety contract,
+/// but gets checked separately (without overflowing) as a guard against the offset silently
+/// wrapping around, which is all too easy on targets with a 16-bit `usize`.
+#[inline]
+#[track_caller]
+fn debug_assert_index_in_bounds<T>(index: usize, len: usize) {
+    if cfg!(debug_assertions) {
+        assert_index_in_bounds(index, len);
+    }
+    debug_assert!(
+        index
+            .checked_mul(mem::size_of::<T>())
+            .is_some_and(|offset| offset <= isize::MAX as usize),
+        "index byte offset overflows `isize`"
+    );
+}
+
+/// Asserts that writing `count` elements into a slice of length `len`,
+/// starting at `offset`, every `stride` elements, stays within bounds.
+#[inline]
+#[track_caller]
+fn assert_strided_in_bounds(len: usize, count: usize, offset: usize, stride: usize) {
+    assert!(stride != 0, "stride must be non-zero");
+
+    if let Some(last) = count.checked_sub(1) {
+        let last_index = last.saturating_mul(stride).saturating_add(offset);
+
+        assert_index_in_bounds(last_index, len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use droptest::prelude::*;
+
+    use super::*;
+
+    fn put_first<P: PutAt<T> + ?Sized, T>(sink: &mut P, value: T) {
+        sink.put_at(0, value);
+    }
+
+    fn write_last<W: WriteAt<T> + ?Sized, T>(sink: &mut W, len: usize, value: T) {
+        sink.write_at(len - 1, value);
+    }
+
+    #[test]
+    fn put_at_slice_and_array() {
+        let registry = DropRegistry::default();
+        let (old_id, old_guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let mut guards = [old_guard];
+        put_first(&mut guards, new_guard);
+
+        assert_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+
+        let mut values = vec![0, 1, 2];
+        put_first(&mut values[..], 42);
+
+        assert_eq!(values, [42, 1, 2]);
+    }
+
+    #[test]
+    fn write_at_slice_and_array() {
+        let registry = DropRegistry::default();
+        let (old_id, old_guard) = registry.new_guard_for(1).by_id();
+        let (new_id, new_guard) = registry.new_guard_for(2).by_id();
+
+        let mut guards = [old_guard];
+        write_last(&mut guards, 1, new_guard);
+
+        assert_no_drop!(registry, old_id);
+        assert_no_drop!(registry, new_id);
+
+        let mut values = [0, 1, 2];
+        write_last(&mut values[..], 3, 42);
+        unsafe { values.write_at_unchecked(0, 43) };
+
+        assert_eq!(values, [43, 1, 42]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for write-only slice of length 3")]
+    fn write_at_array_out_of_bounds() {
+        let mut values = [0, 1, 2];
+        values.write_at(3, 42);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index byte offset overflows `isize`")]
+    fn debug_assert_index_in_bounds_overflow() {
+        debug_assert_index_in_bounds::<u16>(isize::MAX as usize, usize::MAX);
+    }
+
+    #[test]
+    #[cfg(all(target_pointer_width = "16", debug_assertions))]
+    #[should_panic(expected = "index byte offset overflows `isize`")]
+    fn debug_assert_index_in_bounds_overflow_16_bit() {
+        // `0x4000 * 4` would silently wrap around to `0` in 16-bit release arithmetic.
+        debug_assert_index_in_bounds::<u32>(0x4000, 0x8000);
+    }
+
+    #[test]
+    fn debug_assert_index_in_bounds_last_byte() {
+        debug_assert_index_in_bounds::<u8>(isize::MAX as usize, usize::MAX);
+    }
 }
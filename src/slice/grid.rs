@@ -0,0 +1,136 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{VolatileWriteOnlySlice, WriteAt};
+
+/// A write-only **2D grid** with **non-dropping volatile** write access,
+/// e.g. for a device's register file addressed as `[bank][register]`.
+///
+/// The grid is laid out in bank-major order, with the element of register `reg`
+/// within bank `bank` being located at index `bank * stride + reg` of the underlying slice.
+/// Any trailing elements not filling up an entire bank are inaccessible.
+pub struct VolatileWriteOnlyGrid<'a, T: 'a> {
+    slice: VolatileWriteOnlySlice<'a, T>,
+    stride: usize,
+}
+
+impl<'a, T: 'a> VolatileWriteOnlyGrid<'a, T> {
+    /// Forms a grid of `stride` registers per bank from a write-only slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is 0.
+    #[inline]
+    #[track_caller]
+    pub fn new(slice: VolatileWriteOnlySlice<'a, T>, stride: usize) -> Self {
+        assert!(stride != 0, "stride must be non-zero");
+
+        Self { slice, stride }
+    }
+
+    /// Returns the number of banks in the grid.
+    #[inline]
+    pub fn banks(&self) -> usize {
+        self.slice.len() / self.stride
+    }
+
+    /// Returns the number of registers per bank.
+    #[inline]
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Performs a volatile write of register `reg` within bank `bank` with the given value,
+    /// without reading or dropping the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bank` or `reg` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn write_at(&mut self, bank: usize, reg: usize, value: T) {
+        super::assert_index_in_bounds(bank, self.banks());
+        super::assert_index_in_bounds(reg, self.stride);
+
+        // SAFETY: `bank * stride + reg < banks * stride <= len`.
+        unsafe {
+            self.slice
+                .write_at_unchecked(bank * self.stride + reg, value);
+        }
+    }
+
+    /// Converts the grid back into its underlying write-only slice.
+    #[inline]
+    pub fn into_slice(self) -> VolatileWriteOnlySlice<'a, T> {
+        self.slice
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_at() {
+        let mut values = [0_u32; 7];
+
+        let mut grid = VolatileWriteOnlyGrid::new(VolatileWriteOnlySlice::from(&mut values), 3);
+
+        assert_eq!(grid.banks(), 2);
+        assert_eq!(grid.stride(), 3);
+
+        grid.write_at(0, 2, 2);
+        grid.write_at(1, 0, 3);
+        grid.write_at(1, 2, 5);
+
+        assert_eq!(values, [0, 0, 2, 3, 0, 5, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 out of bounds for write-only slice of length 2")]
+    fn write_at_bank_out_of_bounds() {
+        let mut values = [0_u32; 7];
+
+        let mut grid = VolatileWriteOnlyGrid::new(VolatileWriteOnlySlice::from(&mut values), 3);
+        grid.write_at(2, 0, 42);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "index 18446744073709551615 out of bounds for write-only slice of length 2"
+    )]
+    #[cfg(target_pointer_width = "64")]
+    fn write_at_huge_bank() {
+        let mut values = [0_u32; 4];
+
+        let mut grid = VolatileWriteOnlyGrid::new(VolatileWriteOnlySlice::from(&mut values), 2);
+        grid.write_at(usize::MAX, 1, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for write-only slice of length 2")]
+    fn write_at_wrapping_bank() {
+        let mut values = [0_u32; 4];
+
+        let mut grid = VolatileWriteOnlyGrid::new(VolatileWriteOnlySlice::from(&mut values), 2);
+        grid.write_at(usize::MAX / 2 + 1, 1, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for write-only slice of length 3")]
+    fn write_at_reg_out_of_bounds() {
+        let mut values = [0_u32; 6];
+
+        let mut grid = VolatileWriteOnlyGrid::new(VolatileWriteOnlySlice::from(&mut values), 3);
+        grid.write_at(0, 3, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "stride must be non-zero")]
+    fn new_zero_stride() {
+        let mut values = [0_u32; 6];
+
+        let _ = VolatileWriteOnlyGrid::new(VolatileWriteOnlySlice::from(&mut values), 0);
+    }
+}
@@ -0,0 +1,206 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::{
+    convert::TryFrom,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    num::NonZeroUsize,
+    ptr,
+};
+
+use crate::{PutAt, WriteAt, WriteOnlyRef, WriteOnlySlice};
+
+/// A **non-empty** write-only **slice** with **dropping non-volatile** write access.
+///
+/// # Drop semantics
+///
+/// The slice borrows its elements rather than owning them:
+/// dropping the slice itself never drops any of its elements.
+/// Only the old values replaced by [`PutAt::put_at`] get dropped.
+pub struct WriteOnlyNonEmptySlice<'a, T: 'a> {
+    data: *mut T,
+    len: NonZeroUsize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> WriteOnlyNonEmptySlice<'a, T> {
+    /// Returns the number of elements in the slice.
+    ///
+    /// Unlike [`WriteOnlySlice::len`] this returns a [`NonZeroUsize`],
+    /// as the slice is guaranteed to contain at least one element.
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        self.len
+    }
+
+    /// Returns a write-only reference to the first element of the slice.
+    #[inline]
+    pub fn first(&mut self) -> WriteOnlyRef<'_, T> {
+        // SAFETY: the slice is non-empty by definition.
+        unsafe { WriteOnlyRef::new_unchecked(self.data) }
+    }
+
+    /// Returns a write-only reference to the last element of the slice.
+    #[inline]
+    pub fn last(&mut self) -> WriteOnlyRef<'_, T> {
+        // SAFETY: the slice is non-empty by definition.
+        unsafe { WriteOnlyRef::new_unchecked(self.data.add(self.len.get() - 1)) }
+    }
+}
+
+impl<'a, T: 'a> PutAt<T> for WriteOnlyNonEmptySlice<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn put_at(&mut self, index: usize, value: T) {
+        super::assert_index_in_bounds(index, self.len.get());
+
+        unsafe {
+            self.put_at_unchecked(index, value);
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn put_at_unchecked(&mut self, index: usize, value: T) {
+        super::debug_assert_index_in_bounds::<T>(index, self.len.get());
+
+        *self.data.add(index) = value;
+    }
+}
+
+impl<'a, T: 'a> WriteAt<T> for WriteOnlyNonEmptySlice<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        super::assert_index_in_bounds(index, self.len.get());
+
+        unsafe {
+            self.write_at_unchecked(index, value);
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        super::debug_assert_index_in_bounds::<T>(index, self.len.get());
+
+        self.data.add(index).write(value);
+    }
+}
+
+impl<'a, T: 'a> TryFrom<WriteOnlySlice<'a, T>> for WriteOnlyNonEmptySlice<'a, T> {
+    type Error = WriteOnlySlice<'a, T>;
+
+    /// Converts `slice` into a non-empty slice, returning it unchanged as `Err` if it is empty.
+    #[inline]
+    fn try_from(slice: WriteOnlySlice<'a, T>) -> Result<Self, Self::Error> {
+        match NonZeroUsize::new(slice.len) {
+            Some(len) => Ok(Self {
+                data: slice.data,
+                len,
+                _phantom: PhantomData,
+            }),
+            None => Err(slice),
+        }
+    }
+}
+
+impl<'a, T: 'a> From<WriteOnlyNonEmptySlice<'a, T>> for WriteOnlySlice<'a, T> {
+    #[inline]
+    fn from(slice: WriteOnlyNonEmptySlice<'a, T>) -> Self {
+        unsafe { Self::new_unchecked(slice.data, slice.len.get()) }
+    }
+}
+
+// SAFETY: a `WriteOnlyNonEmptySlice` is only ever converted from a `WriteOnlySlice`,
+// taking over its exclusive access to its `len` elements for `'a`. Moving it to another
+// thread thus moves the right to write (and, via `put_at`, to drop) `T`s there, hence `T: Send`.
+unsafe impl<'a, T: 'a> Send for WriteOnlyNonEmptySlice<'a, T> where T: Send {}
+
+// SAFETY: all writes and drops take `&mut self`. The only `&self` method, `len`,
+// merely reads the length, so sharing a `&WriteOnlyNonEmptySlice` is harmless.
+unsafe impl<'a, T: 'a> Sync for WriteOnlyNonEmptySlice<'a, T> where T: Sync {}
+
+/// Compares by identity (i.e. same base pointer and length), not by value.
+impl<'a, T: 'a> PartialEq for WriteOnlyNonEmptySlice<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data) && self.len == other.len
+    }
+}
+
+impl<'a, T: 'a> Eq for WriteOnlyNonEmptySlice<'a, T> {}
+
+/// Hashes by identity (i.e. by base pointer and length), not by value.
+impl<'a, T: 'a> Hash for WriteOnlyNonEmptySlice<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.len.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Put;
+
+    use droptest::prelude::*;
+
+    #[test]
+    fn send_to_thread() {
+        let mut values: Vec<u8> = vec![0; 3];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let mut non_empty = WriteOnlyNonEmptySlice::try_from(slice).ok().unwrap();
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || non_empty.last().put(42));
+        });
+
+        assert_eq!(values, &[0, 0, 42]);
+    }
+
+    #[test]
+    fn try_from() {
+        let mut values: Vec<u8> = vec![0; 3];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let non_empty = WriteOnlyNonEmptySlice::try_from(slice).ok().unwrap();
+
+        assert_eq!(non_empty.len().get(), 3);
+    }
+
+    #[test]
+    fn try_from_empty() {
+        let mut values: Vec<u8> = vec![];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert!(WriteOnlyNonEmptySlice::try_from(slice).is_err());
+    }
+
+    #[test]
+    fn first_and_last() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        let slice = WriteOnlySlice::from(&mut guards[..]);
+        let mut non_empty = WriteOnlyNonEmptySlice::try_from(slice).ok().unwrap();
+        non_empty.first().put(registry.new_guard_for(3));
+        non_empty.last().put(registry.new_guard_for(4));
+
+        assert_eq!(guards[0].value(), &3);
+        assert_eq!(guards[1].value(), &1);
+        assert_eq!(guards[2].value(), &4);
+
+        assert_drop!(registry, old_ids[0]);
+        assert_no_drop!(registry, old_ids[1]);
+        assert_drop!(registry, old_ids[2]);
+        assert_drop_stats!(registry, { created: 5, dropped: 2 });
+    }
+}
@@ -2,15 +2,69 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::{marker::PhantomData, mem};
+use core::{
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    ops::{Range, RangeBounds},
+    ptr, slice,
+};
 
-use crate::{PutAt, PutFromSliceAt, WriteAt, WriteFromSliceAt};
+use crate::{
+    CapacityError, Put, PutAt, PutFromSliceAt, VolatileWriteOnlySlice, Write, WriteAt,
+    WriteFromSliceAt, WriteOnlyRef,
+};
 
 /// A write-only **slice** with **dropping non-volatile** write access.
+///
+/// # Drop semantics
+///
+/// The slice borrows its elements rather than owning them:
+/// dropping the slice itself never drops any of its elements.
+/// Only the old values replaced by the `put` family of methods get dropped.
+///
+/// # Variance
+///
+/// Just like `&'a mut [T]` the slice is covariant in `'a`, but invariant in `T`.
+/// The invariance stems from the `*mut T` pointer it stores, with the
+/// `PhantomData<&'a mut T>` marker merely making it explicit.
+///
+/// Shortening `'a` is allowed:
+///
+/// ```
+/// use write_only::WriteOnlySlice;
+///
+/// fn shorten<'long: 'short, 'short, T>(slice: WriteOnlySlice<'long, T>) -> WriteOnlySlice<'short, T> {
+///     slice
+/// }
+/// ```
+///
+/// Shortening the lifetimes within `T` is thus rejected:
+///
+/// ```compile_fail
+/// use write_only::WriteOnlySlice;
+///
+/// fn shorten<'a, 'short>(slice: WriteOnlySlice<'a, &'static str>) -> WriteOnlySlice<'a, &'short str> {
+///     slice
+/// }
+/// ```
+///
+/// Just as is extending them:
+///
+/// ```compile_fail
+/// use write_only::WriteOnlySlice;
+///
+/// fn extend<'a, 'short>(slice: WriteOnlySlice<'a, &'short str>) -> WriteOnlySlice<'a, &'static str> {
+///     slice
+/// }
+/// ```
 pub struct WriteOnlySlice<'a, T: 'a> {
-    data: *mut T,
-    len: usize,
-    _phantom: PhantomData<&'a T>,
+    pub(super) data: *mut T,
+    pub(super) len: usize,
+    _phantom: PhantomData<&'a mut T>,
+    #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+    _overlap: Option<super::overlap::OverlapGuard>,
 }
 
 impl<'a, T: 'a> WriteOnlySlice<'a, T> {
@@ -50,279 +104,3704 @@ impl<'a, T: 'a> WriteOnlySlice<'a, T> {
     /// source lifetime is safe in the context, such as by providing a helper
     /// function taking the lifetime of a host value for the slice, or by explicit
     /// annotation.
+    #[cfg(feature = "raw")]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn from_raw_parts_mut(data: *mut T, len: usize) -> Self {
+        let slice = Self::new_unchecked(data, len);
+
+        // Register the slice's range, panicking if it overlaps with another such slice.
+        #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+        let slice = Self {
+            _overlap: super::overlap::OverlapGuard::register(data, len),
+            ..slice
+        };
+
+        slice
+    }
+
+    /// Forms a write-only slice from a pointer and a length.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::from_raw_parts_mut`].
+    #[cfg(feature = "raw")]
+    #[deprecated(note = "renamed to `from_raw_parts_mut`")]
     #[inline]
     pub unsafe fn from_raw_parts(data: *mut T, len: usize) -> Self {
+        Self::from_raw_parts_mut(data, len)
+    }
+
+    /// Forms a write-only slice from a non-null pointer and a length.
+    ///
+    /// The `len` argument is the number of **elements**, not the number of bytes.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::from_raw_parts_mut`], with `data` being non-null guaranteed by its type.
+    #[cfg(feature = "raw")]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn from_non_null(data: ptr::NonNull<T>, len: usize) -> Self {
+        let slice = Self::new_unchecked(data.as_ptr(), len);
+
+        // Register the slice's range, panicking if it overlaps with another such slice.
+        #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+        let slice = Self {
+            _overlap: super::overlap::OverlapGuard::register(data.as_ptr(), len),
+            ..slice
+        };
+
+        slice
+    }
+
+    /// Forms a write-only slice of the elements of an `UnsafeCell<[T]>`.
+    ///
+    /// Writing through the slice is the kind of interior mutation `UnsafeCell` permits.
+    /// Yet as the cell is merely borrowed immutably, others may be accessing it too.
+    ///
+    /// # Safety
+    ///
+    /// The elements of `cell` must not be accessed through any other pointer or reference
+    /// for the duration of lifetime `'a`, and no other write-only slice of them
+    /// may be formed in the meantime.
+    #[cfg(feature = "raw")]
+    #[inline]
+    pub unsafe fn from_unsafe_cell(cell: &'a core::cell::UnsafeCell<[T]>) -> Self {
+        let data = cell.get();
+
+        Self::new_unchecked(data as *mut T, data.len())
+    }
+
+    /// Forms a write-only slice from a pointer and a length.
+    ///
+    /// # Safety
+    ///
+    /// Same as for `from_raw_parts_mut`.
+    #[inline]
+    pub(crate) unsafe fn new_unchecked(data: *mut T, len: usize) -> Self {
         debug_assert!(
-            !data.is_null() && (data.align_offset(mem::align_of::<u16>()) == 0),
+            !data.is_null() && (data.align_offset(mem::align_of::<T>()) == 0),
             "attempt to create unaligned or null slice"
         );
         debug_assert!(
             mem::size_of::<T>().saturating_mul(len) <= isize::MAX as usize,
             "attempt to create slice covering at least half the address space"
         );
-        // SAFETY: the caller must uphold the safety contract for `from_raw_parts`.
+        // SAFETY: the caller must uphold the safety contract for `new_unchecked`.
         Self {
             data,
             len,
             _phantom: PhantomData,
+            #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+            _overlap: None,
         }
     }
 
+    /// Returns the number of elements in the slice.
     #[inline]
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns `true` if the slice has a length of 0.
     #[inline]
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
-}
 
-impl<'a, T: 'a> PutAt<T> for WriteOnlySlice<'a, T> {
+    /// Returns the size of the slice in bytes, saturating at `usize::MAX`.
     #[inline]
-    fn put_at(&mut self, index: usize, value: T) {
-        assert!(index < self.len);
-
-        unsafe {
-            self.put_at_unchecked(index, value);
-        }
+    pub fn byte_len(&self) -> usize {
+        self.len.saturating_mul(mem::size_of::<T>())
     }
 
+    /// Returns the size of an individual element of the slice in bytes.
     #[inline]
-    unsafe fn put_at_unchecked(&mut self, index: usize, value: T) {
-        *self.data.add(index) = value;
+    pub fn element_size(&self) -> usize {
+        mem::size_of::<T>()
     }
-}
 
-impl<'a, T: 'a> WriteAt<T> for WriteOnlySlice<'a, T> {
+    /// Returns the number of elements to skip from the start of the slice
+    /// for reaching an address that is a multiple of `align` bytes.
+    ///
+    /// Returns `usize::MAX` if that is impossible.
+    /// Note that the returned offset may well be greater than `self.len()`.
+    ///
+    /// See [`pointer::align_offset`](https://doc.rust-lang.org/std/primitive.pointer.html#method.align_offset)
+    /// for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
     #[inline]
-    fn write_at(&mut self, index: usize, value: T) {
-        assert!(index < self.len);
-
-        unsafe {
-            self.write_at_unchecked(index, value);
-        }
+    #[track_caller]
+    pub fn align_offset(&self, align: usize) -> usize {
+        self.data.align_offset(align)
     }
 
+    /// Returns write-only references to many indices at once.
+    ///
+    /// Returns `None` if any index is out-of-bounds, or if the same index was passed more than once.
     #[inline]
-    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
-        self.data.add(index).write(value);
+    pub fn get_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[WriteOnlyRef<'_, T>; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.len || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        // SAFETY: all indices were checked to be in-bounds and pairwise distinct,
+        // so the returned references cannot alias.
+        Some(core::array::from_fn(|i| unsafe {
+            WriteOnlyRef::new_unchecked(self.data.add(indices[i]))
+        }))
     }
-}
 
-impl<'a, T: 'a> PutFromSliceAt<T> for WriteOnlySlice<'a, T> {
+    /// Puts the value returned by `f(index)` at each `index` of the slice, dropping the old values.
     #[inline]
-    fn put_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    pub fn put_indexed_with<F>(&mut self, mut f: F)
     where
-        T: Clone,
+        F: FnMut(usize) -> T,
     {
-        assert!(offset + src.len() <= self.len);
-
-        // SAFETY: `self` is valid for `self.len()` elements by definition,
-        // and `src` was checked to have a length less than `self.len() - offset`.
-        // The slices cannot overlap because mutable references are exclusive.
-
-        for (index, item) in src.iter().enumerate() {
+        for index in 0..self.len {
             unsafe {
-                *self.data.add(offset + index) = item.clone();
+                self.put_at_unchecked(index, f(index));
             }
         }
     }
-}
 
-impl<'a, T: 'a> WriteFromSliceAt<T> for WriteOnlySlice<'a, T> {
+    /// Writes the value returned by `f(index)` at each `index` of the slice,
+    /// without reading or dropping the old values.
     #[inline]
-    fn write_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    pub fn write_indexed_with<F>(&mut self, mut f: F)
     where
-        T: Clone,
+        F: FnMut(usize) -> T,
     {
-        assert!(offset + src.len() <= self.len);
-
-        // SAFETY: `self` is valid for `self.len()` elements by definition,
-        // and `src` was checked to have a length less than `self.len() - offset`.
-        // The slices cannot overlap because mutable references are exclusive.
-
-        for (index, item) in src.iter().enumerate() {
+        for index in 0..self.len {
             unsafe {
-                self.data.add(offset + index).write(item.clone());
+                self.write_at_unchecked(index, f(index));
             }
         }
     }
 
+    /// Returns an iterator over `chunk_size` elements of the slice at a time,
+    /// starting at the beginning of the slice, borrowing rather than consuming `self`.
+    ///
+    /// The chunks are write-only slices and do not overlap. If `chunk_size` does not
+    /// divide the length of the slice, then the last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
     #[inline]
-    fn write_copying_from_slice_at(&mut self, src: &[T], offset: usize)
-    where
-        T: Copy,
-    {
-        assert!(src.len() <= self.len - offset);
+    #[track_caller]
+    pub fn chunks_ref(&mut self, chunk_size: usize) -> impl Iterator<Item = WriteOnlySlice<'_, T>> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
 
-        // SAFETY: `self` is valid for `self.len()` elements by definition,
-        // and `src` was checked to have a length less than `self.len - offset`.
-        // The slices cannot overlap because mutable references are exclusive.
-        unsafe {
-            self.data
-                .add(offset)
-                .copy_from_nonoverlapping(src.as_ptr(), src.len());
-        }
+        let data = self.data;
+        let len = self.len;
+
+        // SAFETY: each chunk covers a distinct in-bounds sub-range of `self`,
+        // which stays mutably borrowed for as long as the chunks are alive.
+        (0..len).step_by(chunk_size).map(move |start| unsafe {
+            WriteOnlySlice::new_unchecked(data.add(start), chunk_size.min(len - start))
+        })
     }
-}
 
-impl<'a, T: 'a> From<&'a mut [T]> for WriteOnlySlice<'a, T> {
+    /// Returns an iterator over `chunk_size` elements of the slice at a time,
+    /// starting at the end of the slice.
+    ///
+    /// The chunks are write-only slices and do not overlap. If `chunk_size` does not
+    /// divide the length of the slice, then the last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
     #[inline]
-    fn from(slice: &'a mut [T]) -> Self {
-        unsafe { Self::from_raw_parts(slice.as_mut_ptr(), slice.len()) }
-    }
-}
+    #[track_caller]
+    pub fn rchunks(self, chunk_size: usize) -> impl Iterator<Item = WriteOnlySlice<'a, T>> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let data = self.data;
+        let len = self.len;
 
-    use droptest::prelude::*;
+        // SAFETY: each chunk covers a distinct in-bounds sub-range of `self`.
+        (1..=len).rev().step_by(chunk_size).map(move |end| unsafe {
+            let start = end.saturating_sub(chunk_size);
+            WriteOnlySlice::new_unchecked(data.add(start), end - start)
+        })
+    }
 
-    #[test]
-    fn from_raw_parts() {
-        let registry = DropRegistry::default();
-        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+    /// Returns a writer performing **non-dropping non-volatile** writes **without bounds checks**,
+    /// borrowing rather than consuming `self`.
+    ///
+    /// This hoists the bounds check out of tight loops whose index range
+    /// has been validated up front, letting each write be branch-free.
+    ///
+    /// # Safety
+    ///
+    /// Every index passed to [`UncheckedWriter::write`](crate::UncheckedWriter::write) must be less than `self.len()`.
+    /// Writing to an out-of-bounds index is undefined behavior.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn writer_unchecked(&mut self) -> crate::UncheckedWriter<'_, T> {
+        crate::UncheckedWriter::new_unchecked(self.data, self.len)
+    }
 
-        let reference = unsafe { WriteOnlySlice::from_raw_parts(&mut guards, 3) };
+    /// Flushes the CPU's data caches for the memory covered by the slice,
+    /// e.g. for making a batch of non-volatile writes visible to a DMA engine
+    /// that does not snoop the caches.
+    ///
+    /// With the `cache-flush` feature enabled on `x86`/`x86_64` (with SSE2)
+    /// this writes back and invalidates every cache line overlapping the slice
+    /// via `clflush`, followed by an `mfence`.
+    ///
+    /// On all other targets, or with the feature disabled, this merely issues
+    /// a sequentially consistent [`fence`](core::sync::atomic::fence), as an extension
+    /// point for platform-specific cache maintenance to be inserted into.
+    #[inline]
+    pub fn cache_flush_range(&self) {
+        #[cfg(all(
+            feature = "cache-flush",
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ))]
+        {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::{_mm_clflush, _mm_mfence};
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::{_mm_clflush, _mm_mfence};
 
-        std::mem::drop(reference);
+            // The smallest cache line size of any `clflush`-capable CPU,
+            // so that no line gets skipped on CPUs with smaller lines.
+            const CACHE_LINE: usize = 32;
 
-        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+            let start = self.data as *const u8;
+            let misalignment = start.addr() % CACHE_LINE;
+            let end = misalignment + self.byte_len();
 
-        std::mem::drop(guards);
+            // SAFETY: `clflush` faults on addresses that are not mapped readable.
+            // Every flushed line overlaps the slice's (non-empty) byte range, which
+            // is valid memory, so each line lies within pages that are mapped.
+            // Empty slices (whose pointer may be dangling) flush no lines at all.
+            unsafe {
+                if self.byte_len() != 0 {
+                    let mut offset = 0;
+                    while offset < end {
+                        _mm_clflush(start.wrapping_sub(misalignment).wrapping_add(offset));
+                        offset += CACHE_LINE;
+                    }
+                }
+                _mm_mfence();
+            }
+        }
 
-        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+        #[cfg(not(all(
+            feature = "cache-flush",
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        )))]
+        core::sync::atomic::fence(core::sync::atomic::Ordering::SeqCst);
     }
 
-    #[test]
-    fn from() {
-        let registry = DropRegistry::default();
-        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+    /// Swaps all elements in `self` with those in `other`.
+    ///
+    /// The old values of `self` get moved into `other` (and vice versa),
+    /// without ever being exposed through `self`. No values get dropped.
+    ///
+    /// The length of `other` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    #[inline]
+    #[track_caller]
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        assert!(
+            self.len == other.len(),
+            "destination and source slices have different lengths"
+        );
 
-        let reference = WriteOnlySlice::from(&mut guards[..]);
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `other` was checked to have the same length.
+        // The slices cannot overlap because mutable references are exclusive.
+        unsafe {
+            ptr::swap_nonoverlapping(self.data, other.as_mut_ptr(), self.len);
+        }
+    }
 
-        std::mem::drop(reference);
+    /// Swaps all elements in `self` with those in the write-only slice `other`.
+    ///
+    /// Neither slice's old values get exposed: they merely trade places,
+    /// e.g. for swapping a pair of back buffers wholesale. No values get dropped.
+    ///
+    /// The two slices must not overlap, which is guaranteed for slices formed from
+    /// mutable borrows, and required by the safety contracts of the raw-pointer constructors.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    #[inline]
+    #[track_caller]
+    pub fn swap_nonoverlapping(&mut self, other: &mut WriteOnlySlice<'_, T>) {
+        assert!(
+            self.len == other.len,
+            "destination and source slices have different lengths"
+        );
 
-        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+        // SAFETY: both slices are valid for `self.len()` elements by definition,
+        // and cannot overlap because write-only slices are exclusive.
+        unsafe {
+            ptr::swap_nonoverlapping(self.data, other.data, self.len);
+        }
+    }
 
-        std::mem::drop(guards);
+    /// Rotates the slice in-place such that the first `mid` elements
+    /// move to the end while the last `self.len() - mid` elements move to the front.
+    ///
+    /// The elements are merely moved, never read, duplicated or dropped,
+    /// so this works for any `T`, regardless of `Copy` or `Clone`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `mid` is greater than the length of the slice.
+    #[inline]
+    #[track_caller]
+    pub fn rotate_left(&mut self, mid: usize) {
+        super::assert_position_in_bounds(mid, self.len);
 
-        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+        // SAFETY: `self` is valid for `self.len()` initialized elements by definition,
+        // and `<[T]>::rotate_left` only ever moves them around, never exposing their values.
+        unsafe { slice::from_raw_parts_mut(self.data, self.len) }.rotate_left(mid);
     }
 
-    #[test]
-    fn put_at() {
-        let registry = DropRegistry::default();
-        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
-            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
-        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+    /// Rotates the slice in-place such that the first `self.len() - k` elements
+    /// move to the end while the last `k` elements move to the front.
+    ///
+    /// The elements are merely moved, never read, duplicated or dropped,
+    /// so this works for any `T`, regardless of `Copy` or `Clone`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `k` is greater than the length of the slice.
+    #[inline]
+    #[track_caller]
+    pub fn rotate_right(&mut self, k: usize) {
+        super::assert_position_in_bounds(k, self.len);
 
-        let mut slice = WriteOnlySlice::from(&mut guards[..]);
-        slice.put_at(1, new_guard);
+        // SAFETY: `self` is valid for `self.len()` initialized elements by definition,
+        // and `<[T]>::rotate_right` only ever moves them around, never exposing their values.
+        unsafe { slice::from_raw_parts_mut(self.data, self.len) }.rotate_right(k);
+    }
 
-        assert_eq!(guards[1].id(), new_id);
-        assert_eq!(guards[1].value(), &3);
+    /// Divides one write-only slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding
+    /// the index `mid` itself) and the second will contain all
+    /// indices from `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    #[inline]
+    #[track_caller]
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        super::assert_position_in_bounds(mid, self.len);
 
-        assert_drop!(registry, old_ids[1]);
-        assert_drop_stats!(registry, { created: 4, dropped: 1 });
+        // SAFETY: `[data, data + mid)` and `[data + mid, data + len)`
+        // are both in-bounds and do not overlap.
+        unsafe {
+            (
+                Self::new_unchecked(self.data, mid),
+                Self::new_unchecked(self.data.add(mid), self.len - mid),
+            )
+        }
     }
 
-    #[test]
-    #[should_panic]
-    fn put_at_out_of_bounds() {
-        let registry = DropRegistry::default();
-        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
-        let new_guard = registry.new_guard_for(3);
+    /// Divides the write-only slice into two at an index, borrowing `self`.
+    ///
+    /// Like [`split_at`](Self::split_at), but leaves `self` usable
+    /// once both halves have been dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    #[inline]
+    #[track_caller]
+    pub fn split_at_ref(&mut self, mid: usize) -> (WriteOnlySlice<'_, T>, WriteOnlySlice<'_, T>) {
+        super::assert_position_in_bounds(mid, self.len);
 
-        let mut slice = WriteOnlySlice::from(&mut guards[..]);
-        slice.put_at(10, new_guard);
+        // SAFETY: `[data, data + mid)` and `[data + mid, data + len)`
+        // are both in-bounds and do not overlap, and both borrow `self`.
+        unsafe {
+            (
+                WriteOnlySlice::new_unchecked(self.data, mid),
+                WriteOnlySlice::new_unchecked(self.data.add(mid), self.len - mid),
+            )
+        }
     }
 
-    #[test]
-    fn write_at() {
-        let registry = DropRegistry::default();
-        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
-            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
-        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
-
-        let mut slice = WriteOnlySlice::from(&mut guards[..]);
-        slice.write_at(1, new_guard);
+    /// Puts the value at `index` to the given value converted into `T`, dropping the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn put_at_into<U>(&mut self, index: usize, value: U)
+    where
+        U: Into<T>,
+    {
+        self.put_at(index, value.into());
+    }
 
-        assert_eq!(guards[1].id(), new_id);
-        assert_eq!(guards[1].value(), &3);
+    /// Performs a write of a memory location with the given value converted into `T`,
+    /// without reading or dropping the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn write_at_into<U>(&mut self, index: usize, value: U)
+    where
+        U: Into<T>,
+    {
+        self.write_at(index, value.into());
+    }
 
-        assert_no_drop!(registry, old_ids[1]);
-        assert_drop_stats!(registry, { created: 4, dropped: 0 });
+    /// Returns a short-lived write-only slice with **non-dropping volatile** write access to the same memory,
+    /// borrowing rather than consuming `self`.
+    #[inline]
+    pub fn as_volatile(&mut self) -> VolatileWriteOnlySlice<'_, T> {
+        // SAFETY: `self` stays mutably borrowed for as long as the returned slice is alive.
+        unsafe { VolatileWriteOnlySlice::new_unchecked(self.data, self.len) }
     }
 
-    #[test]
-    #[should_panic]
-    fn write_at_out_of_bounds() {
-        let registry = DropRegistry::default();
-        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
-        let new_guard = registry.new_guard_for(3);
+    /// Returns a write-only reference to the first `N` elements of the slice as an array,
+    /// and a write-only slice of the remaining elements.
+    ///
+    /// Returns `None` if the slice has fewer than `N` elements.
+    #[inline]
+    pub fn split_first_chunk<const N: usize>(self) -> Option<(WriteOnlyRef<'a, [T; N]>, Self)> {
+        if self.len < N {
+            return None;
+        }
 
-        let mut slice = WriteOnlySlice::from(&mut guards[..]);
-        slice.write_at(10, new_guard);
+        // SAFETY: `[data, data + N)` and `[data + N, data + len)`
+        // are both in-bounds and do not overlap.
+        unsafe {
+            Some((
+                WriteOnlyRef::new_unchecked(self.data as *mut [T; N]),
+                Self::new_unchecked(self.data.add(N), self.len - N),
+            ))
+        }
     }
 
-    #[test]
-    fn put_cloning_from_slice_at() {
-        let registry = DropRegistry::default();
-        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
-            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
-        let new_guards: Vec<_> = (5..8).map(|i| registry.new_guard_for(i)).collect();
+    /// Splits off the first `n` elements, returning them as a write-only slice,
+    /// and advances `self` to the remaining elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    #[inline]
+    #[track_caller]
+    pub fn take(&mut self, n: usize) -> WriteOnlySlice<'a, T> {
+        super::assert_position_in_bounds(n, self.len);
 
-        let mut slice = WriteOnlySlice::from(&mut guards[..]);
-        slice.put_cloning_from_slice_at(&new_guards[..], 1);
+        // SAFETY: `[data, data + n)` and `[data + n, data + len)`
+        // are both in-bounds and do not overlap.
+        unsafe {
+            let head = Self::new_unchecked(self.data, n);
+            self.data = self.data.add(n);
+            self.len -= n;
+            head
+        }
+    }
 
-        assert_ne!(guards[1].id(), old_ids[1]);
-        assert_eq!(guards[1].value(), &5);
-        assert_ne!(guards[2].id(), old_ids[1]);
-        assert_eq!(guards[2].value(), &6);
+    /// Puts each item yielded by `iter` in order, starting at index `0`, dropping the old values,
+    /// expecting it to yield exactly `self.len()` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer or more than `self.len()` items.
+    #[inline]
+    #[track_caller]
+    pub fn put_all_from_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        for index in 0..self.len {
+            let Some(item) = iter.next() else {
+                panic!(
+                    "iterator yielded fewer items than the slice's length of {}",
+                    self.len
+                );
+            };
+
+            unsafe {
+                self.put_at_unchecked(index, item);
+            }
+        }
+
+        assert!(
+            iter.next().is_none(),
+            "iterator yielded more items than the slice's length of {}",
+            self.len
+        );
+    }
+
+    /// Writes each item yielded by `iter` in order, starting at index `0`, without reading or dropping the old values,
+    /// expecting it to yield exactly `self.len()` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer or more than `self.len()` items.
+    #[inline]
+    #[track_caller]
+    pub fn write_all_from_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+
+        for index in 0..self.len {
+            let Some(item) = iter.next() else {
+                panic!(
+                    "iterator yielded fewer items than the slice's length of {}",
+                    self.len
+                );
+            };
+
+            unsafe {
+                self.write_at_unchecked(index, item);
+            }
+        }
+
+        assert!(
+            iter.next().is_none(),
+            "iterator yielded more items than the slice's length of {}",
+            self.len
+        );
+    }
+
+    /// Splits the slice into a write-only slice of `N`-element arrays,
+    /// starting at the beginning of the slice, and a remainder slice
+    /// with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    #[inline]
+    #[track_caller]
+    pub fn as_chunks<const N: usize>(self) -> (WriteOnlySlice<'a, [T; N]>, WriteOnlySlice<'a, T>) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let chunks = self.len / N;
+        let (head, tail) = self.split_at(chunks * N);
+
+        // SAFETY: `N` consecutive `T`s have the same layout as `[T; N]`.
+        let head = unsafe { WriteOnlySlice::new_unchecked(head.data as *mut [T; N], chunks) };
+
+        (head, tail)
+    }
+
+    /// Splits the slice into a remainder slice with length strictly less than `N`,
+    /// and a write-only slice of `N`-element arrays, ending at the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    #[inline]
+    #[track_caller]
+    pub fn as_rchunks<const N: usize>(self) -> (WriteOnlySlice<'a, T>, WriteOnlySlice<'a, [T; N]>) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let chunks = self.len / N;
+        let remainder = self.len - chunks * N;
+        let (head, tail) = self.split_at(remainder);
+
+        // SAFETY: `N` consecutive `T`s have the same layout as `[T; N]`.
+        let tail = unsafe { WriteOnlySlice::new_unchecked(tail.data as *mut [T; N], chunks) };
+
+        (head, tail)
+    }
+
+    /// Unflattens a write-only slice into a write-only slice of `N`-element arrays,
+    /// of length `self.len() / N`.
+    ///
+    /// Returns `None` if `N` is zero, or if the slice's length is not a multiple of `N`.
+    #[inline]
+    pub fn unflatten<const N: usize>(self) -> Option<WriteOnlySlice<'a, [T; N]>> {
+        if N == 0 || !self.len.is_multiple_of(N) {
+            return None;
+        }
+
+        // SAFETY: `N` consecutive `T`s have the same layout as `[T; N]`.
+        Some(unsafe { WriteOnlySlice::new_unchecked(self.data as *mut [T; N], self.len / N) })
+    }
+
+    /// Copies all elements from `src` into the elements of `self` within `range`, using a memcpy.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` is out of bounds,
+    /// or if its length differs from the length of `src`.
+    #[inline]
+    #[track_caller]
+    pub fn set_slice(&mut self, range: Range<usize>, src: &[T])
+    where
+        T: Copy,
+    {
+        assert!(
+            range.start <= range.end,
+            "slice index starts at {} but ends at {}",
+            range.start,
+            range.end
+        );
+        super::assert_range_in_bounds(range.start, range.len(), self.len);
+        assert!(
+            range.len() == src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        self.write_copying_from_slice_at(src, range.start);
+    }
+
+    /// Clones all elements from `src` into the elements of `self` within `range`,
+    /// dropping the old values.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` is out of bounds,
+    /// or if its length differs from the length of `src`.
+    #[inline]
+    #[track_caller]
+    pub fn put_slice(&mut self, range: Range<usize>, src: &[T])
+    where
+        T: Clone,
+    {
+        assert!(
+            range.start <= range.end,
+            "slice index starts at {} but ends at {}",
+            range.start,
+            range.end
+        );
+        super::assert_range_in_bounds(range.start, range.len(), self.len);
+        assert!(
+            range.len() == src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        self.put_cloning_from_slice_at(src, range.start);
+    }
+
+    /// Copies all elements from `src` into `self`, using a memcpy.
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    #[inline]
+    #[track_caller]
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        assert_eq!(
+            self.len,
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        self.write_copying_from_slice_at(src, 0);
+    }
+
+    /// Clones all elements from `src` into `self`, dropping the old values.
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    #[inline]
+    #[track_caller]
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        assert_eq!(
+            self.len,
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+
+        self.put_cloning_from_slice_at(src, 0);
+    }
+
+    /// Puts the value at `index` to the given value, dropping the old value, returning an error instead of panicking if `index` is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `index` is out of bounds, dropping `value`.
+    #[inline]
+    pub fn try_put_at(&mut self, index: usize, value: T) -> Result<(), CapacityError> {
+        CapacityError::check(index.saturating_add(1), self.len)?;
+
+        unsafe {
+            self.put_at_unchecked(index, value);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the value at `index` without reading or dropping the old value, returning an error instead of panicking if `index` is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `index` is out of bounds, dropping `value`.
+    #[inline]
+    pub fn try_write_at(&mut self, index: usize, value: T) -> Result<(), CapacityError> {
+        CapacityError::check(index.saturating_add(1), self.len)?;
+
+        unsafe {
+            self.write_at_unchecked(index, value);
+        }
+
+        Ok(())
+    }
+
+    /// Clones the elements from `src` into `self`, starting at `offset`, dropping the old values.
+    ///
+    /// Same as [`PutFromSliceAt::put_cloning_from_slice_at`], but accepting any source
+    /// that can be viewed as a slice, such as an array or a `Vec<T>`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn put_cloning_from_slice_at(&mut self, src: &(impl AsRef<[T]> + ?Sized), offset: usize)
+    where
+        T: Clone,
+    {
+        PutFromSliceAt::put_cloning_from_slice_at(self, src.as_ref(), offset);
+    }
+
+    /// Copies all elements from `src` into `self`, starting at `offset`, dropping the old values, using a memcpy.
+    ///
+    /// Same as [`PutFromSliceAt::put_copying_from_slice_at`], but accepting any source
+    /// that can be viewed as a slice, such as an array or a `Vec<T>`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn put_copying_from_slice_at(&mut self, src: &(impl AsRef<[T]> + ?Sized), offset: usize)
+    where
+        T: Copy,
+    {
+        PutFromSliceAt::put_copying_from_slice_at(self, src.as_ref(), offset);
+    }
+
+    /// Clones the elements from `src` into `self`, starting at `offset`, without reading or dropping the old values.
+    ///
+    /// Same as [`WriteFromSliceAt::write_cloning_from_slice_at`], but accepting any source
+    /// that can be viewed as a slice, such as an array or a `Vec<T>`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn write_cloning_from_slice_at(&mut self, src: &(impl AsRef<[T]> + ?Sized), offset: usize)
+    where
+        T: Clone,
+    {
+        WriteFromSliceAt::write_cloning_from_slice_at(self, src.as_ref(), offset);
+    }
+
+    /// Copies all elements from `src` into `self`, starting at `offset`, without reading or dropping the old values, using a memcpy.
+    ///
+    /// Same as [`WriteFromSliceAt::write_copying_from_slice_at`], but accepting any source
+    /// that can be viewed as a slice, such as an array or a `Vec<T>`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn write_copying_from_slice_at(&mut self, src: &(impl AsRef<[T]> + ?Sized), offset: usize)
+    where
+        T: Copy,
+    {
+        WriteFromSliceAt::write_copying_from_slice_at(self, src.as_ref(), offset);
+    }
+
+    /// Clones the elements from `src` into `self`, starting at `offset`, dropping the old values, returning an error instead of panicking if `src` does not fit into `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `offset + src.len()` is greater than `self.len()`.
+    #[inline]
+    pub fn try_put_cloning_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+    ) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        let src = src.as_ref();
+
+        CapacityError::check(offset.saturating_add(src.len()), self.len)?;
+
+        self.put_cloning_from_slice_at(src, offset);
+
+        Ok(())
+    }
+
+    /// Clones the elements from `src` into `self`, starting at `offset`, dropping the old values,
+    /// returning the number of old values that got overwritten (and thus dropped).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn put_cloning_from_slice_at_counted(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+    ) -> usize
+    where
+        T: Clone,
+    {
+        let src = src.as_ref();
+
+        self.put_cloning_from_slice_at(src, offset);
+
+        src.len()
+    }
+
+    /// Puts `f(&src[k])` at `offset + k` for each element of `src`, dropping the old values.
+    ///
+    /// If `f` panics, the elements put before the panic are kept.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn put_mapped_from_slice_at<A, F>(&mut self, src: &[A], offset: usize, mut f: F)
+    where
+        F: FnMut(&A) -> T,
+    {
+        let count = src.len();
+
+        super::assert_range_in_bounds(offset, count, self.len);
+
+        for (index, item) in src.iter().enumerate() {
+            // SAFETY: `offset + index` was checked to be less than `self.len`.
+            unsafe { self.put_at_unchecked(offset + index, f(item)) };
+        }
+    }
+
+    /// Writes `f(&src[k])` at `offset + k` for each element of `src`, without dropping the old values.
+    ///
+    /// If `f` panics, the elements written before the panic are kept.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn write_mapped_from_slice_at<A, F>(&mut self, src: &[A], offset: usize, mut f: F)
+    where
+        F: FnMut(&A) -> T,
+    {
+        let count = src.len();
+
+        super::assert_range_in_bounds(offset, count, self.len);
+
+        for (index, item) in src.iter().enumerate() {
+            // SAFETY: `offset + index` was checked to be less than `self.len`.
+            unsafe { self.write_at_unchecked(offset + index, f(item)) };
+        }
+    }
+
+    /// Clones the elements from `src` into `self`, starting at `offset`, without dropping the old values, returning an error instead of panicking if `src` does not fit into `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `offset + src.len()` is greater than `self.len()`.
+    #[inline]
+    pub fn try_write_cloning_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+    ) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        let src = src.as_ref();
+
+        CapacityError::check(offset.saturating_add(src.len()), self.len)?;
+
+        self.write_cloning_from_slice_at(src, offset);
+
+        Ok(())
+    }
+
+    /// Copies all elements from `src` into `self`, starting at `offset`, returning an error instead of panicking if `src` does not fit into `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `offset + src.len()` is greater than `self.len()`.
+    #[inline]
+    pub fn try_write_copying_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+    ) -> Result<(), CapacityError>
+    where
+        T: Copy,
+    {
+        let src = src.as_ref();
+
+        CapacityError::check(offset.saturating_add(src.len()), self.len)?;
+
+        self.write_copying_from_slice_at(src, offset);
+
+        Ok(())
+    }
+
+    /// Copies all elements from `src` into `self`, starting at `offset`, using a memmove.
+    ///
+    /// Unlike [`WriteFromSliceAt::write_copying_from_slice_at`] this tolerates `src`
+    /// overlapping with `self`. Slices obtained from mutable borrows can never overlap,
+    /// so prefer this only where `self` was constructed from raw pointers
+    /// (e.g. via `from_raw_parts_mut`) and `src` may alias it.
+    ///
+    /// Note that `src` is a raw slice pointer, rather than a `&[T]`:
+    /// a shared reference aliasing `self` while `self` gets written to
+    /// would be undefined behavior on its own, regardless of the copy.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    ///
+    /// # Safety
+    ///
+    /// `src` must be [valid](ptr#safety) for reads of `src.len()` elements,
+    /// and properly aligned.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn move_copying_from_slice_at(&mut self, src: *const [T], offset: usize)
+    where
+        T: Copy,
+    {
+        let count = src.len();
+
+        super::assert_range_in_bounds(offset, count, self.len);
+
+        // An empty `src` is a no-op. Bailing out before any pointer arithmetic
+        // also avoids forming `data + offset` for `offset == len`.
+        if count == 0 {
+            return;
+        }
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len - offset`.
+        // The caller guarantees `src` to be readable.
+        // `ptr::copy` allows for the slices to overlap.
+        ptr::copy(src as *const T, self.data.add(offset), count);
+    }
+
+    /// Copies all elements from the write-only slice `src` into `self`, starting at `offset`, using a memcpy.
+    ///
+    /// This is meant for buffer-to-buffer transfers between two write-only regions,
+    /// such as DMA buffers, without reading or dropping the old values of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    ///
+    /// # Safety
+    ///
+    /// Copying from `src` reads its elements, so the memory behind `src`
+    /// must be initialized and readable by the CPU.
+    ///
+    /// The memory behind `src` must not overlap with the `src.len()` elements
+    /// of `self` starting at `offset`. Write-only slices created via
+    /// `WriteOnlySlice::from_raw_parts_mut` may alias one another.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn copy_from_write_only(&mut self, src: &WriteOnlySlice<'_, T>, offset: usize)
+    where
+        T: Copy,
+    {
+        let count = src.len;
+
+        super::assert_range_in_bounds(offset, count, self.len);
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len - offset`.
+        // The caller guarantees `src` to be readable and not to overlap
+        // with the destination range.
+        self.data
+            .add(offset)
+            .copy_from_nonoverlapping(src.data, count);
+    }
+
+    /// Copies the elements from `src` into every `stride`-th element of `self`, starting at `offset`,
+    /// i.e. `src[k]` into `self[offset + k * stride]`, without reading or dropping the old values.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `stride` is zero, or if `offset + (src.len() - 1) * stride`
+    /// is out of bounds (for non-empty `src`).
+    #[inline]
+    #[track_caller]
+    pub fn write_strided_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+        stride: usize,
+    ) where
+        T: Copy,
+    {
+        let src = src.as_ref();
+
+        super::assert_strided_in_bounds(self.len, src.len(), offset, stride);
+
+        // SAFETY: the last (and thus every) index was checked to be in-bounds.
+        for (k, item) in src.iter().enumerate() {
+            unsafe {
+                self.write_at_unchecked(offset + k * stride, *item);
+            }
+        }
+    }
+
+    /// Clones the elements from `src` into every `stride`-th element of `self`, starting at `offset`,
+    /// i.e. `src[k]` into `self[offset + k * stride]`, dropping the old values.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `stride` is zero, or if `offset + (src.len() - 1) * stride`
+    /// is out of bounds (for non-empty `src`).
+    #[inline]
+    #[track_caller]
+    pub fn put_strided_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+        stride: usize,
+    ) where
+        T: Clone,
+    {
+        let src = src.as_ref();
+
+        super::assert_strided_in_bounds(self.len, src.len(), offset, stride);
+
+        // SAFETY: the last (and thus every) index was checked to be in-bounds.
+        for (k, item) in src.iter().enumerate() {
+            unsafe {
+                self.put_at_unchecked(offset + k * stride, item.clone());
+            }
+        }
+    }
+
+    /// Puts clones of `value` into each element of the slice, dropping the old values.
+    ///
+    /// The last element receives `value` itself, rather than a clone.
+    ///
+    /// # Panics
+    ///
+    /// If cloning `value`, or dropping an old value of `self` panics,
+    /// then every slot of `self` is still left holding a valid value:
+    /// slots before the failing one hold their new values, slots after it their old values.
+    /// A slot whose old value panicked on drop holds its new value.
+    /// Each old value is dropped at most once.
+    ///
+    /// For `T: Copy` prefer [`write_fill`](Self::write_fill), which neither clones `value` nor drops the old values.
+    #[inline]
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.fill_range(.., value);
+    }
+
+    /// Puts clones of `value` into each element of the slice that starts at or after
+    /// the first address that is a multiple of `align` bytes, dropping the old values.
+    ///
+    /// Returns the index of the first filled element, i.e. the length of the skipped,
+    /// unaligned head of the slice, which is `self.len()` if nothing got filled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    #[inline]
+    #[track_caller]
+    pub fn aligned_fill(&mut self, align: usize, value: T) -> usize
+    where
+        T: Clone,
+    {
+        let start = self.align_offset(align).min(self.len);
+
+        self.fill_range(start.., value);
+
+        start
+    }
+
+    /// Writes copies of `value` into each element of the slice, without reading or dropping the old values.
+    ///
+    /// For single-byte `T` this lowers to a `memset`, just like `<[u8]>::fill` does.
+    #[inline]
+    pub fn write_fill(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        if mem::size_of::<T>() == 1 {
+            // SAFETY: `self` is valid for writes of `self.len()` elements by definition,
+            // and a `MaybeUninit<T>` may hold any bytes, including uninitialized ones,
+            // so viewing the elements as such never reads or asserts their old values.
+            let slice =
+                unsafe { slice::from_raw_parts_mut(self.data as *mut MaybeUninit<T>, self.len) };
+            slice.fill(MaybeUninit::new(value));
+            return;
+        }
+
+        // SAFETY: all indices are within `0..len`, which is in-bounds.
+        for index in 0..self.len {
+            unsafe {
+                self.data.add(index).write(value);
+            }
+        }
+    }
+
+    /// Puts clones of `value` into each element of the slice within `range`, dropping the old values.
+    ///
+    /// The last element of the range receives `value` itself, rather than a clone.
+    /// For an empty range `value` simply gets dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds, or if its start is greater than its end.
+    ///
+    /// If cloning `value`, or dropping an old value of `self` panics,
+    /// the slots are left in the same state as described for [`WriteOnlySlice::fill`].
+    #[inline]
+    #[track_caller]
+    pub fn fill_range<R>(&mut self, range: R, value: T)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let Range { start, end } = super::resolve_range(range, self.len);
+
+        if start == end {
+            return;
+        }
+
+        let last = end - 1;
+
+        // SAFETY: all indices are within `start..end`, which is in-bounds.
+
+        // Each clone is stored before its slot's old value gets dropped,
+        // so a panicking `Clone` or `Drop` leaves every slot holding a valid value.
+        for index in start..last {
+            unsafe {
+                let old = ptr::replace(self.data.add(index), value.clone());
+                mem::drop(old);
+            }
+        }
+
+        unsafe {
+            let old = ptr::replace(self.data.add(last), value);
+            mem::drop(old);
+        }
+    }
+
+    /// Writes clones of `value` into each element of the slice within `range`,
+    /// without reading or dropping the old values.
+    ///
+    /// The last element of the range receives `value` itself, rather than a clone.
+    /// For an empty range `value` simply gets dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds, or if its start is greater than its end.
+    #[inline]
+    #[track_caller]
+    pub fn write_fill_range<R>(&mut self, range: R, value: T)
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let Range { start, end } = super::resolve_range(range, self.len);
+
+        if start == end {
+            return;
+        }
+
+        let last = end - 1;
+
+        // SAFETY: all indices are within `start..end`, which is in-bounds.
+        for index in start..last {
+            unsafe {
+                self.data.add(index).write(value.clone());
+            }
+        }
+
+        unsafe {
+            self.data.add(last).write(value);
+        }
+    }
+
+    /// Writes `pattern` repeatedly into the slice, from `offset` to the end,
+    /// without reading or dropping the old values.
+    ///
+    /// If the remaining length is not a multiple of `N`, the last repetition
+    /// gets truncated to the prefix of `pattern` that still fits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero, or if `offset` is greater than `self.len()`.
+    #[inline]
+    #[track_caller]
+    pub fn write_pattern<const N: usize>(&mut self, pattern: [T; N], offset: usize)
+    where
+        T: Copy,
+    {
+        assert!(N != 0, "pattern must be non-empty");
+        super::assert_position_in_bounds(offset, self.len);
+
+        let mut index = offset;
+
+        // SAFETY: each copy covers `[index, index + count)`, with `index + count <= self.len`.
+        // The pattern lives on the stack, so it cannot overlap with `self`.
+        unsafe {
+            while self.len - index >= N {
+                self.data
+                    .add(index)
+                    .copy_from_nonoverlapping(pattern.as_ptr(), N);
+                index += N;
+            }
+
+            self.data
+                .add(index)
+                .copy_from_nonoverlapping(pattern.as_ptr(), self.len - index);
+        }
+    }
+
+    /// Puts `T::default()` into each element of the slice, dropping the old values.
+    #[inline]
+    pub fn put_default(&mut self)
+    where
+        T: Default,
+    {
+        self.put_indexed_with(|_| T::default());
+    }
+
+    /// Writes `T::default()` into each element of the slice, without reading or dropping the old values.
+    #[inline]
+    pub fn write_default(&mut self)
+    where
+        T: Default,
+    {
+        self.write_indexed_with(|_| T::default());
+    }
+
+    /// Converts the write-only slice back into a regular mutable slice,
+    /// re-introducing read-access.
+    ///
+    /// # Safety
+    ///
+    /// The slice's values must be initialized, and the caller must have exclusive
+    /// access to them for the duration of lifetime `'a`.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn assume_readable(self) -> &'a mut [T] {
+        slice::from_raw_parts_mut(self.data, self.len)
+    }
+
+    /// Divides one write-only slice into two halves.
+    ///
+    /// For odd lengths the first half gets the extra element,
+    /// i.e. it splits at `len - len / 2`.
+    #[inline]
+    pub fn halve(self) -> (Self, Self) {
+        let mid = self.len - self.len / 2;
+
+        self.split_at(mid)
+    }
+}
+
+impl<'a, T: 'a, const N: usize> WriteOnlySlice<'a, [T; N]> {
+    /// Flattens a write-only slice of `N`-element arrays into a write-only slice of
+    /// their elements, of length `self.len() * N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the resulting slice would overflow a `usize`
+    /// (which is only possible for zero-sized `T`).
+    #[inline]
+    #[track_caller]
+    pub fn flatten(self) -> WriteOnlySlice<'a, T> {
+        let len = self.len.checked_mul(N).expect("slice len overflow");
+
+        // SAFETY: `[T; N]` has the same layout as `N` consecutive `T`s.
+        unsafe { WriteOnlySlice::new_unchecked(self.data as *mut T, len) }
+    }
+}
+
+impl<'a, T: 'a> WriteOnlySlice<'a, MaybeUninit<T>> {
+    /// Forms a write-only slice from a slice of uninitialized values.
+    ///
+    /// As `MaybeUninit<T>` never drops its value writing to the slice
+    /// never drops any (uninitialized) old values either.
+    #[inline]
+    pub fn from_uninit(slice: &'a mut [MaybeUninit<T>]) -> Self {
+        Self::from(slice)
+    }
+
+    /// Converts the slice of uninitialized values into a slice of initialized values.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the slice must have been initialized (e.g. via [`WriteOnlySlice::write_init_at`]).
+    /// Calling this when the content is not yet fully initialized is undefined behavior.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn assume_init(self) -> WriteOnlySlice<'a, T> {
+        WriteOnlySlice::new_unchecked(self.data as *mut T, self.len)
+    }
+
+    /// Forms a write-only slice of the spare capacity of `vec`,
+    /// i.e. of its uninitialized elements past `vec.len()`.
+    ///
+    /// Once the first `n` of its elements have been written to,
+    /// they can be made part of `vec` via `vec.set_len(vec.len() + n)`:
+    ///
+    /// ```
+    /// use write_only::prelude::*;
+    ///
+    /// let mut values: Vec<u32> = Vec::with_capacity(4);
+    /// values.push(1);
+    ///
+    /// let mut spare = WriteOnlySlice::from_spare_capacity(&mut values);
+    /// spare.write_init_at(0, 2);
+    /// spare.write_init_at(1, 3);
+    ///
+    /// // SAFETY: the two elements past the end have just been initialized.
+    /// unsafe { values.set_len(3) };
+    ///
+    /// assert_eq!(values, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn from_spare_capacity(vec: &'a mut Vec<T>) -> Self {
+        Self::from(vec.spare_capacity_mut())
+    }
+
+    /// Writes the initialized value `value` into the uninitialized slot at `index`.
+    ///
+    /// Use [`WriteAt::write_at`] for writing (possibly uninitialized) `MaybeUninit<T>` values instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn write_init_at(&mut self, index: usize, value: T) {
+        self.write_at(index, MaybeUninit::new(value));
+    }
+}
+
+impl<'a> WriteOnlySlice<'a, u8> {
+    /// Copies the UTF-8 bytes of `s` into `self`, starting at `offset`.
+    ///
+    /// Returns the number of bytes written, i.e. `s.len()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + s.len()` is greater than `self.len()`.
+    #[inline]
+    #[track_caller]
+    pub fn write_str_at(&mut self, offset: usize, s: &str) -> usize {
+        self.write_copying_from_slice_at(s.as_bytes(), offset);
+
+        s.len()
+    }
+
+    /// Copies the UTF-8 bytes of `s` into `self`, starting at `offset`.
+    ///
+    /// Returns the number of bytes written, i.e. `s.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `offset + s.len()` is greater than `self.len()`.
+    #[inline]
+    pub fn try_write_str_at(&mut self, offset: usize, s: &str) -> Result<usize, CapacityError> {
+        self.try_write_copying_from_slice_at(s.as_bytes(), offset)?;
+
+        Ok(s.len())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> WriteOnlySlice<'a, u8> {
+    /// Reads bytes from `reader` into `self`, starting at `offset`,
+    /// until either `self` is full, or `reader` reached EOF.
+    ///
+    /// Returns the total number of bytes written.
+    ///
+    /// As `Read::read` requires read-access to its buffer, which `self` cannot provide,
+    /// the bytes are read into a small intermediate buffer on the stack first,
+    /// from which they then get copied into `self`.
+    ///
+    /// Reads that fail with `io::ErrorKind::Interrupted` are retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns any other error returned by `reader`, leaving the bytes that were
+    /// read before the error already written into `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is greater than `self.len()`.
+    #[track_caller]
+    pub fn write_from_reader<R>(&mut self, offset: usize, reader: &mut R) -> std::io::Result<usize>
+    where
+        R: std::io::Read,
+    {
+        super::assert_position_in_bounds(offset, self.len);
+
+        const BUFFER_LEN: usize = 512;
+
+        let mut buffer = [0u8; BUFFER_LEN];
+        let mut written = 0;
+
+        while offset + written < self.len {
+            let remaining = self.len - offset - written;
+            let chunk = &mut buffer[..remaining.min(BUFFER_LEN)];
+
+            match reader.read(chunk) {
+                Ok(0) => break,
+                Ok(count) => {
+                    self.write_copying_from_slice_at(&chunk[..count], offset + written);
+                    written += count;
+                }
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+/// Treats a slice of length 1 as a sink for a single value.
+impl<'a, T: 'a> Put<T> for WriteOnlySlice<'a, T> {
+    /// Puts the slice's single element to the given value, dropping the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is not 1.
+    #[inline]
+    #[track_caller]
+    fn put(&mut self, value: T) {
+        assert_eq!(self.len, 1, "expected a slice of length 1");
+
+        // SAFETY: the slice was checked to have exactly one element.
+        unsafe {
+            self.put_at_unchecked(0, value);
+        }
+    }
+}
+
+/// Treats a slice of length 1 as a sink for a single value.
+impl<'a, T: 'a> Write<T> for WriteOnlySlice<'a, T> {
+    /// Writes the slice's single element with the given value, without reading or dropping the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is not 1.
+    #[inline]
+    #[track_caller]
+    fn write(&mut self, value: T) {
+        assert_eq!(self.len, 1, "expected a slice of length 1");
+
+        // SAFETY: the slice was checked to have exactly one element.
+        unsafe {
+            self.write_at_unchecked(0, value);
+        }
+    }
+}
+
+impl<'a, T: 'a> PutAt<T> for WriteOnlySlice<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn put_at(&mut self, index: usize, value: T) {
+        super::assert_index_in_bounds(index, self.len);
+
+        unsafe {
+            self.put_at_unchecked(index, value);
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn put_at_unchecked(&mut self, index: usize, value: T) {
+        super::debug_assert_index_in_bounds::<T>(index, self.len);
+
+        *self.data.add(index) = value;
+    }
+}
+
+impl<'a, T: 'a> WriteAt<T> for WriteOnlySlice<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        super::assert_index_in_bounds(index, self.len);
+
+        unsafe {
+            self.write_at_unchecked(index, value);
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        super::debug_assert_index_in_bounds::<T>(index, self.len);
+
+        self.data.add(index).write(value);
+    }
+}
+
+impl<'a, T: 'a> PutFromSliceAt<T> for WriteOnlySlice<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn put_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Clone,
+    {
+        super::assert_range_in_bounds(offset, src.len(), self.len);
+
+        // An empty `src` is a no-op. Bailing out before any pointer arithmetic
+        // also avoids forming `data + offset` for `offset == len`.
+        if src.is_empty() {
+            return;
+        }
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len() - offset`.
+        // The slices cannot overlap because mutable references are exclusive.
+
+        // Each clone is stored before its slot's old value gets dropped,
+        // so a panicking `Drop` leaves every slot holding a valid value.
+        for (index, item) in src.iter().enumerate() {
+            unsafe {
+                let old = ptr::replace(self.data.add(offset + index), item.clone());
+                mem::drop(old);
+            }
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn put_copying_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Copy,
+    {
+        let count = src.len();
+
+        super::assert_range_in_bounds(offset, count, self.len);
+
+        // An empty `src` is a no-op. Bailing out before any pointer arithmetic
+        // also avoids forming `data + offset` for `offset == len`.
+        if count == 0 {
+            return;
+        }
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len - offset`.
+        // The slices cannot overlap because mutable references are exclusive.
+        //
+        // Dropping the old values in place is a no-op for `Copy` types,
+        // so that this lowers to a single `memcpy`, just like the write path.
+        unsafe {
+            let dst = self.data.add(offset);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(dst, count));
+            dst.copy_from_nonoverlapping(src.as_ptr(), count);
+        }
+    }
+}
+
+impl<'a, T: 'a> WriteFromSliceAt<T> for WriteOnlySlice<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn write_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Clone,
+    {
+        super::assert_range_in_bounds(offset, src.len(), self.len);
+
+        // An empty `src` is a no-op. Bailing out before any pointer arithmetic
+        // also avoids forming `data + offset` for `offset == len`.
+        if src.is_empty() {
+            return;
+        }
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len() - offset`.
+        // The slices cannot overlap because mutable references are exclusive.
+
+        for (index, item) in src.iter().enumerate() {
+            unsafe {
+                self.data.add(offset + index).write(item.clone());
+            }
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn write_copying_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Copy,
+    {
+        let count = src.len();
+
+        super::assert_range_in_bounds(offset, count, self.len);
+
+        // An empty `src` is a no-op. Bailing out before any pointer arithmetic
+        // also avoids forming `data + offset` for `offset == len`.
+        if count == 0 {
+            return;
+        }
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len - offset`.
+        // The slices cannot overlap because mutable references are exclusive.
+        //
+        // No further branching past these checks, so that this lowers
+        // to a single `memcpy`, just like `<[T]>::copy_from_slice` does.
+        unsafe {
+            self.data
+                .add(offset)
+                .copy_from_nonoverlapping(src.as_ptr(), count);
+        }
+    }
+}
+
+impl<'a, T: 'a> From<&'a mut [T]> for WriteOnlySlice<'a, T> {
+    #[inline]
+    fn from(slice: &'a mut [T]) -> Self {
+        unsafe { Self::new_unchecked(slice.as_mut_ptr(), slice.len()) }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> From<&'a mut [T; N]> for WriteOnlySlice<'a, T> {
+    #[inline]
+    fn from(array: &'a mut [T; N]) -> Self {
+        unsafe { Self::new_unchecked(array.as_mut_ptr(), N) }
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for WriteOnlySlice<'a, T> {
+    type Item = WriteOnlyRef<'a, T>;
+    type IntoIter = IntoIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            data: self.data,
+            start: 0,
+            end: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A consuming iterator over the elements of a [`WriteOnlySlice`],
+/// yielding a write-only reference per element.
+pub struct IntoIter<'a, T: 'a> {
+    data: *mut T,
+    start: usize,
+    end: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> Iterator for IntoIter<'a, T> {
+    type Item = WriteOnlyRef<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let index = self.start;
+        self.start += 1;
+
+        // SAFETY: `index` is in-bounds and gets yielded only once.
+        Some(unsafe { WriteOnlyRef::new_unchecked(self.data.add(index)) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for IntoIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: `self.end` is in-bounds and gets yielded only once.
+        Some(unsafe { WriteOnlyRef::new_unchecked(self.data.add(self.end)) })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for IntoIter<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for IntoIter<'a, T> {}
+
+// SAFETY: a `WriteOnlySlice` has exclusive access to its `len` elements for `'a`, and
+// its splitting methods (e.g. `split_at`) only ever hand out disjoint ranges of them.
+// Moving one to another thread thus cannot race with writes through any other slice,
+// but moves the right to write (and, via `put_at`, to drop) `T`s there, hence `T: Send`.
+unsafe impl<'a, T: 'a> Send for WriteOnlySlice<'a, T> where T: Send {}
+
+// SAFETY: all writes and drops take `&mut self`. The `&self` methods only read the
+// pointer and length (`len`, `align_offset`) or flush caches without touching any values
+// (`cache_flush_range`), so sharing a `&WriteOnlySlice` across threads is harmless.
+unsafe impl<'a, T: 'a> Sync for WriteOnlySlice<'a, T> where T: Sync {}
+
+/// Compares by identity (i.e. same base pointer and length), not by value.
+impl<'a, T: 'a> PartialEq for WriteOnlySlice<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data) && self.len == other.len
+    }
+}
+
+impl<'a, T: 'a> Eq for WriteOnlySlice<'a, T> {}
+
+/// Hashes by identity (i.e. by base pointer and length), not by value.
+impl<'a, T: 'a> Hash for WriteOnlySlice<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.len.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use droptest::prelude::*;
+
+    #[test]
+    #[cfg(feature = "raw")]
+    #[allow(clippy::drop_non_drop)]
+    fn from_raw_parts_mut() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let reference = unsafe { WriteOnlySlice::from_raw_parts_mut(guards.as_mut_ptr(), 3) };
+
+        std::mem::drop(reference);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+
+        std::mem::drop(guards);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    }
+
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    fn from() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let reference = WriteOnlySlice::from(&mut guards[..]);
+
+        std::mem::drop(reference);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+
+        std::mem::drop(guards);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    }
+
+    #[test]
+    fn from_array() {
+        let mut values = [0, 1, 2];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+
+        assert_eq!(slice.len(), 3);
+
+        slice.write_at(1, 42);
+
+        assert_eq!(values, [0, 42, 2]);
+    }
+
+    #[test]
+    fn put_at() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_at(1, new_guard);
+
+        assert_eq!(guards[1].id(), new_id);
+        assert_eq!(guards[1].value(), &3);
+
+        assert_drop!(registry, old_ids[1]);
+        assert_drop_stats!(registry, { created: 4, dropped: 1 });
+    }
+
+    #[test]
+    #[should_panic]
+    fn put_at_out_of_bounds() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+        let new_guard = registry.new_guard_for(3);
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_at(10, new_guard);
+    }
+
+    #[test]
+    fn write_at() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.write_at(1, new_guard);
+
+        assert_eq!(guards[1].id(), new_id);
+        assert_eq!(guards[1].value(), &3);
+
+        assert_no_drop!(registry, old_ids[1]);
+        assert_drop_stats!(registry, { created: 4, dropped: 0 });
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_at_out_of_bounds() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+        let new_guard = registry.new_guard_for(3);
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.write_at(10, new_guard);
+    }
+
+    #[test]
+    fn put_cloning_from_slice_at() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let new_guards: Vec<_> = (5..8).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_cloning_from_slice_at(&new_guards[..], 1);
+
+        assert_ne!(guards[1].id(), old_ids[1]);
+        assert_eq!(guards[1].value(), &5);
+        assert_ne!(guards[2].id(), old_ids[1]);
+        assert_eq!(guards[2].value(), &6);
+        assert_ne!(guards[3].id(), old_ids[2]);
+        assert_eq!(guards[3].value(), &7);
+
+        assert_drop!(registry, old_ids[1]);
+        assert_drop!(registry, old_ids[2]);
+        assert_drop!(registry, old_ids[3]);
+        assert_drop_stats!(registry, { created: 11, dropped: 3 });
+    }
+
+    #[test]
+    fn put_cloning_from_slice_at_panicking_drop() {
+        #[derive(Clone)]
+        struct PanickingDrop<'a> {
+            guard: DropGuard<'a, i32>,
+            panics: bool,
+        }
+
+        impl<'a> Drop for PanickingDrop<'a> {
+            fn drop(&mut self) {
+                if self.panics {
+                    panic!("drop of {} panicked", self.guard.value());
+                }
+            }
+        }
+
+        let registry = DropRegistry::default();
+        let (old_ids, mut items): (Vec<_>, Vec<_>) = (0..5)
+            .map(|i| {
+                let (id, guard) = registry.new_guard_for(i).by_id();
+                (
+                    id,
+                    PanickingDrop {
+                        guard,
+                        panics: i == 2,
+                    },
+                )
+            })
+            .unzip();
+        let new_items: Vec<_> = (5..8)
+            .map(|i| PanickingDrop {
+                guard: registry.new_guard_for(i),
+                panics: false,
+            })
+            .collect();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut slice = WriteOnlySlice::from(&mut items[..]);
+            slice.put_cloning_from_slice_at(&new_items[..], 1);
+        }));
+
+        assert!(result.is_err());
+
+        assert_eq!(items[1].guard.value(), &5);
+        assert_eq!(items[2].guard.value(), &6);
+        assert_eq!(items[3].guard.value(), &3);
+
+        assert_drop!(registry, old_ids[1]);
+        assert_drop!(registry, old_ids[2]);
+        assert_no_drop!(registry, old_ids[3]);
+        assert_drop_stats!(registry, { created: 10, dropped: 2 });
+    }
+
+    #[test]
+    fn write_cloning_from_slice_at() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let new_guards: Vec<_> = (5..8).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.write_cloning_from_slice_at(&new_guards[..], 1);
+
+        assert_ne!(guards[1].id(), old_ids[1]);
+        assert_eq!(guards[1].value(), &5);
+        assert_ne!(guards[2].id(), old_ids[1]);
+        assert_eq!(guards[2].value(), &6);
         assert_ne!(guards[3].id(), old_ids[2]);
         assert_eq!(guards[3].value(), &7);
 
+        assert_no_drop!(registry, old_ids[1]);
+        assert_no_drop!(registry, old_ids[2]);
+        assert_no_drop!(registry, old_ids[3]);
+        assert_drop_stats!(registry, { created: 11, dropped: 0 });
+    }
+
+    #[test]
+    fn put_copying_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+        let new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_copying_from_slice_at(&new_values[..], 1);
+
+        assert_eq!(values, &[0, 5, 6, 7, 4]);
+    }
+
+    #[test]
+    fn write_copying_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+        let new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_copying_from_slice_at(&new_values[..], 1);
+
+        assert_eq!(values, &[0, 5, 6, 7, 4]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn from_slice_at_empty_at_end() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+        let empty: [DropGuard<'_, i32>; 0] = [];
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_cloning_from_slice_at(&empty, 3);
+        slice.write_cloning_from_slice_at(&empty, 3);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+
+        let mut values = [0_u32; 3];
+        let data = values.as_mut_ptr();
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        slice.put_copying_from_slice_at(&[], 3);
+        slice.write_copying_from_slice_at(&[], 3);
+        unsafe { slice.move_copying_from_slice_at(ptr::slice_from_raw_parts(data, 0), 3) };
+
+        assert_eq!(values, [0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for write-only slice of length 3")]
+    fn from_slice_at_empty_out_of_bounds() {
+        let mut values = [0_u32; 3];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        slice.write_copying_from_slice_at(&[], 4);
+    }
+
+    #[test]
+    fn write_copying_from_vec() {
+        let mut values: Vec<_> = (0..5).collect();
+        let new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_copying_from_slice_at(&new_values, 0);
+        slice.put_copying_from_slice_at(&new_values, 2);
+
+        assert_eq!(values, &[5, 6, 5, 6, 7]);
+    }
+
+    #[test]
+    fn write_copying_from_slice_at_dyn() {
+        let mut values: Vec<u32> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let dyn_slice: &mut dyn WriteFromSliceAt<u32> = &mut slice;
+        dyn_slice.write_copying_from_slice_at(&[7, 8], 1);
+
+        assert_eq!(values, &[0, 7, 8, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn move_copying_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        unsafe { slice.move_copying_from_slice_at(&[5, 6][..], 3) };
+
+        assert_eq!(values, &[0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    #[cfg(feature = "raw")]
+    fn move_copying_from_slice_at_overlapping() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let data = values.as_mut_ptr();
+        let mut slice = unsafe { WriteOnlySlice::from_raw_parts_mut(data, 5) };
+        unsafe { slice.move_copying_from_slice_at(ptr::slice_from_raw_parts(data, 4), 1) };
+
+        assert_eq!(values, &[0, 0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn move_copying_from_slice_at_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        unsafe { slice.move_copying_from_slice_at(&[5, 6][..], 4) };
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn copy_from_write_only() {
+        let mut values: Vec<_> = (0..5).collect();
+        let mut new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let src = WriteOnlySlice::from(&mut new_values[..]);
+        unsafe { slice.copy_from_write_only(&src, 1) };
+
+        assert_eq!(values, &[0, 5, 6, 7, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn copy_from_write_only_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+        let mut new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let src = WriteOnlySlice::from(&mut new_values[..]);
+        unsafe { slice.copy_from_write_only(&src, 3) };
+    }
+
+    #[test]
+    fn write_suffix_from_slice() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let pos = slice.write_suffix_from_slice(0, &[5, 6]);
+        let pos = slice.write_suffix_from_slice(pos, &[7]);
+
+        assert_eq!(pos, 3);
+        assert_eq!(values, &[5, 6, 7, 3, 4]);
+    }
+
+    #[test]
+    fn get_many() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let [mut first, mut second] = slice.get_many([3, 1]).unwrap();
+        first.put(30);
+        second.put(10);
+
+        assert_eq!(values, &[0, 10, 2, 30, 4]);
+    }
+
+    #[test]
+    fn get_many_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.get_many([1, 5]).is_none());
+    }
+
+    #[test]
+    fn get_many_overlapping() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.get_many([1, 2, 1]).is_none());
+    }
+
+    #[test]
+    fn fill() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.fill(registry.new_guard_for(42));
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[42, 42, 42]);
+
+        for old_id in old_ids {
+            assert_drop!(registry, old_id);
+        }
+        assert_drop_stats!(registry, { created: 6, dropped: 3 });
+    }
+
+    #[test]
+    fn fill_panicking_clone() {
+        use std::cell::Cell;
+
+        struct PanickingClone<'a> {
+            guard: DropGuard<'a, i32>,
+            clones: &'a Cell<usize>,
+        }
+
+        impl<'a> Clone for PanickingClone<'a> {
+            fn clone(&self) -> Self {
+                self.clones.set(self.clones.get() + 1);
+                if self.clones.get() == 3 {
+                    panic!("clone of {} panicked", self.guard.value());
+                }
+                Self {
+                    guard: self.guard.clone(),
+                    clones: self.clones,
+                }
+            }
+        }
+
+        let registry = DropRegistry::default();
+        let clones = Cell::new(0);
+        let (old_ids, mut items): (Vec<_>, Vec<_>) = (0..5)
+            .map(|i| {
+                let (id, guard) = registry.new_guard_for(i).by_id();
+                (
+                    id,
+                    PanickingClone {
+                        guard,
+                        clones: &clones,
+                    },
+                )
+            })
+            .unzip();
+        let (value_id, value_guard) = registry.new_guard_for(42).by_id();
+        let value = PanickingClone {
+            guard: value_guard,
+            clones: &clones,
+        };
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut slice = WriteOnlySlice::from(&mut items[..]);
+            slice.fill(value);
+        }));
+
+        assert!(result.is_err());
+
+        let values: Vec<_> = items.iter().map(|item| *item.guard.value()).collect();
+        assert_eq!(values, &[42, 42, 2, 3, 4]);
+
+        assert_drop!(registry, old_ids[0]);
+        assert_drop!(registry, old_ids[1]);
+        assert_no_drop!(registry, old_ids[2]);
+        assert_no_drop!(registry, old_ids[3]);
+        assert_no_drop!(registry, old_ids[4]);
+        assert_drop!(registry, value_id);
+        assert_drop_stats!(registry, { created: 8, dropped: 3 });
+    }
+
+    #[test]
+    fn fill_range() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.fill_range(1..=2, registry.new_guard_for(42));
+        slice.fill_range(4.., registry.new_guard_for(43));
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 42, 42, 3, 43]);
+
+        assert_no_drop!(registry, old_ids[0]);
+        assert_drop!(registry, old_ids[1]);
+        assert_drop!(registry, old_ids[2]);
+        assert_no_drop!(registry, old_ids[3]);
+        assert_drop!(registry, old_ids[4]);
+        assert_drop_stats!(registry, { created: 8, dropped: 3 });
+    }
+
+    #[test]
+    fn fill_range_empty() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.fill_range(2..2, registry.new_guard_for(42));
+        slice.fill_range(3.., registry.new_guard_for(43));
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 1, 2]);
+
+        assert_drop_stats!(registry, { created: 5, dropped: 2 });
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for write-only slice of length 3")]
+    fn fill_range_out_of_bounds() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.fill_range(..=3, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 2 but ends at 1")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn fill_range_reversed() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.fill_range(2..1, 42);
+    }
+
+    #[test]
+    fn write_fill() {
+        let mut bytes = [0_u8; 5];
+
+        let mut slice = WriteOnlySlice::from(&mut bytes[1..]);
+        slice.write_fill(0xAB);
+
+        assert_eq!(bytes, [0x00, 0xAB, 0xAB, 0xAB, 0xAB]);
+
+        let mut flags = [false; 3];
+
+        let mut slice = WriteOnlySlice::from(&mut flags);
+        slice.write_fill(true);
+
+        assert_eq!(flags, [true; 3]);
+
+        let mut values = [0_u32; 3];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        slice.write_fill(0xDEAD_BEEF);
+
+        assert_eq!(values, [0xDEAD_BEEF; 3]);
+    }
+
+    #[test]
+    fn write_fill_uninit() {
+        let mut bytes = [MaybeUninit::new(1_u8); 3];
+
+        let mut slice = WriteOnlySlice::from_uninit(&mut bytes);
+        slice.write_fill(MaybeUninit::uninit());
+        slice.write_init_at(1, 42);
+
+        assert_eq!(unsafe { bytes[1].assume_init() }, 42);
+    }
+
+    #[test]
+    fn write_fill_range() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..4).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.write_fill_range(..2, registry.new_guard_for(42));
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[42, 42, 2, 3]);
+
+        for old_id in old_ids {
+            assert_no_drop!(registry, old_id);
+        }
+        assert_drop_stats!(registry, { created: 6, dropped: 0 });
+    }
+
+    #[test]
+    fn write_pattern() {
+        let mut bytes = [0_u8; 11];
+
+        let mut slice = WriteOnlySlice::from(&mut bytes);
+        slice.write_pattern([0xDE, 0xAD, 0xBE, 0xEF], 1);
+
+        assert_eq!(
+            bytes,
+            [0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0xDE, 0xAD, 0xBE, 0xEF, 0xDE, 0xAD]
+        );
+
+        let mut slice = WriteOnlySlice::from(&mut bytes);
+        slice.write_pattern([1, 2], 11);
+
+        assert_eq!(bytes[10], 0xAD);
+    }
+
+    #[test]
+    #[should_panic(expected = "pattern must be non-empty")]
+    fn write_pattern_empty() {
+        let mut bytes = [0_u8; 4];
+
+        let mut slice = WriteOnlySlice::from(&mut bytes);
+        slice.write_pattern([], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 4")]
+    fn write_pattern_out_of_bounds() {
+        let mut bytes = [0_u8; 4];
+
+        let mut slice = WriteOnlySlice::from(&mut bytes);
+        slice.write_pattern([1], 5);
+    }
+
+    #[test]
+    fn put_indexed_with() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_indexed_with(|index| registry.new_guard_for(index * 10));
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 10, 20]);
+
+        assert_drop_stats!(registry, { created: 6, dropped: 3 });
+    }
+
+    #[test]
+    fn write_indexed_with() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.write_indexed_with(|index| registry.new_guard_for(index * 10));
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 10, 20]);
+
+        assert_drop_stats!(registry, { created: 6, dropped: 0 });
+    }
+
+    #[test]
+    fn chunks_ref() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        let lens: Vec<_> = slice
+            .chunks_ref(2)
+            .map(|mut chunk| {
+                chunk.put_at(0, 42);
+                chunk.len()
+            })
+            .collect();
+
+        assert_eq!(lens, &[2, 2, 1]);
+
+        slice.put_at(1, 43);
+
+        assert_eq!(values, &[42, 43, 42, 3, 42]);
+    }
+
+    #[test]
+    fn rchunks() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+
+        let lens: Vec<_> = slice
+            .rchunks(2)
+            .enumerate()
+            .map(|(index, mut chunk)| {
+                let len = chunk.len();
+                chunk.put_at(len - 1, 10 + index);
+                len
+            })
+            .collect();
+
+        assert_eq!(lens, &[2, 2, 1]);
+        assert_eq!(values, &[12, 1, 11, 3, 10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn rchunks_zero_chunk_size() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let _ = slice.rchunks(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_ref_zero() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let _ = slice.chunks_ref(0);
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::HashSet;
+
+        let mut values = [0, 1, 2];
+        let ptr = values.as_mut_ptr();
+
+        let (whole, prefix, suffix, whole_again) = unsafe {
+            (
+                WriteOnlySlice::new_unchecked(ptr, 3),
+                WriteOnlySlice::new_unchecked(ptr, 2),
+                WriteOnlySlice::new_unchecked(ptr.add(1), 2),
+                WriteOnlySlice::new_unchecked(ptr, 3),
+            )
+        };
+
+        assert!(whole == whole_again);
+        assert!(whole != prefix);
+        assert!(prefix != suffix);
+
+        let set: HashSet<_> = [whole, prefix, suffix, whole_again].into_iter().collect();
+
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn from_spare_capacity() {
+        let registry = DropRegistry::default();
+        let mut guards = Vec::with_capacity(3);
+        guards.push(registry.new_guard_for(0));
+
+        let mut slice = WriteOnlySlice::from_spare_capacity(&mut guards);
+
+        assert!(slice.len() >= 2);
+
+        slice.write_init_at(0, registry.new_guard_for(1));
+
+        unsafe { guards.set_len(2) };
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 1]);
+
+        assert_drop_stats!(registry, { created: 2, dropped: 0 });
+    }
+
+    #[test]
+    fn write_init_at() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<MaybeUninit<DropGuard<i32>>> =
+            (0..3).map(|_| MaybeUninit::uninit()).collect();
+        let (new_id, new_guard) = registry.new_guard_for(42).by_id();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.write_init_at(1, new_guard);
+
+        let guard = unsafe { guards[1].assume_init_read() };
+
+        assert_eq!(guard.id(), new_id);
+        assert_eq!(guard.value(), &42);
+
+        assert_drop_stats!(registry, { created: 1, dropped: 0 });
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn from_uninit_assume_init() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<MaybeUninit<DropGuard<i32>>> =
+            (0..2).map(|_| MaybeUninit::uninit()).collect();
+        let (new_id, new_guard) = registry.new_guard_for(42).by_id();
+
+        {
+            let mut slice = WriteOnlySlice::from_uninit(&mut guards[..]);
+            slice.write_init_at(0, registry.new_guard_for(0));
+            slice.write_init_at(1, registry.new_guard_for(1));
+
+            let mut slice = unsafe { slice.assume_init() };
+            slice.put_at(1, new_guard);
+        }
+
+        assert_drop_stats!(registry, { created: 3, dropped: 1 });
+
+        let guards: Vec<_> = guards
+            .into_iter()
+            .map(|guard| unsafe { guard.assume_init() })
+            .collect();
+
+        assert_eq!(guards[1].id(), new_id);
+        assert_eq!(guards[1].value(), &42);
+    }
+
+    #[test]
+    fn write_from_reader() {
+        let mut values: Vec<u8> = vec![0; 1000];
+        let bytes: Vec<u8> = (0..800).map(|i| i as u8).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let written = slice.write_from_reader(100, &mut &bytes[..]).unwrap();
+
+        assert_eq!(written, 800);
+        assert_eq!(&values[..100], &[0; 100][..]);
+        assert_eq!(&values[100..900], &bytes[..]);
+        assert_eq!(&values[900..], &[0; 100][..]);
+    }
+
+    #[test]
+    fn write_from_reader_until_full() {
+        let mut values: Vec<u8> = vec![0; 5];
+        let bytes: Vec<u8> = (0..10).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let written = slice.write_from_reader(2, &mut &bytes[..]).unwrap();
+
+        assert_eq!(written, 3);
+        assert_eq!(values, &[0, 0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn byte_len() {
+        let mut values: Vec<u32> = vec![0; 5];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.element_size(), 4);
+        assert_eq!(slice.byte_len(), 20);
+    }
+
+    #[test]
+    fn align_offset() {
+        let mut values = [0_u64; 16];
+
+        let slice = WriteOnlySlice::from(&mut values);
+        assert_eq!(slice.align_offset(8), 0);
+
+        let slice = WriteOnlySlice::from(&mut values[1..]);
+        assert_eq!(slice.align_offset(8), 0);
+        assert_eq!(slice.align_offset(1), 0);
+
+        let head = WriteOnlySlice::from(&mut values).align_offset(64);
+        assert!(head < 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "align_offset: align is not a power-of-two")]
+    fn align_offset_not_power_of_two() {
+        let mut values = [0_u8; 4];
+
+        let slice = WriteOnlySlice::from(&mut values);
+        slice.align_offset(3);
+    }
+
+    #[test]
+    fn aligned_fill() {
+        let mut values = [0_u64; 16];
+
+        let head = WriteOnlySlice::from(&mut values).align_offset(64);
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        assert_eq!(slice.aligned_fill(64, 42), head);
+
+        assert!(values[..head].iter().all(|&value| value == 0));
+        assert!(values[head..].iter().all(|&value| value == 42));
+        assert_eq!((values.as_ptr() as usize + head * 8) % 64, 0);
+
+        let mut values = [0_u64; 0];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        assert_eq!(slice.aligned_fill(64, 42), 0);
+    }
+
+    #[test]
+    fn cache_flush_range() {
+        let mut values = [0_u8; 100];
+
+        let mut slice = WriteOnlySlice::from(&mut values[3..]);
+        slice.write_copying_from_slice_at(&[1, 2, 3], 94);
+        slice.cache_flush_range();
+
+        let slice = WriteOnlySlice::from(&mut values[..0]);
+        slice.cache_flush_range();
+
+        assert_eq!(values[97..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn cache_flush_range_empty_dangling() {
+        let mut values: Vec<u32> = Vec::new();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        slice.cache_flush_range();
+    }
+
+    #[test]
+    fn swap_with_slice() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (new_ids, mut new_guards): (Vec<_>, Vec<_>) =
+            (3..6).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.swap_with_slice(&mut new_guards[..]);
+
+        assert_eq!(
+            guards.iter().map(|guard| guard.id()).collect::<Vec<_>>(),
+            new_ids
+        );
+        assert_eq!(
+            new_guards
+                .iter()
+                .map(|guard| guard.id())
+                .collect::<Vec<_>>(),
+            old_ids
+        );
+
+        assert_drop_stats!(registry, { created: 6, dropped: 0 });
+    }
+
+    #[test]
+    fn swap_nonoverlapping() {
+        let registry = DropRegistry::default();
+        let (front_ids, mut front): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (back_ids, mut back): (Vec<_>, Vec<_>) =
+            (3..6).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        {
+            let mut front_slice = WriteOnlySlice::from(&mut front[..]);
+            let mut back_slice = WriteOnlySlice::from(&mut back[..]);
+            front_slice.swap_nonoverlapping(&mut back_slice);
+        }
+
+        assert_eq!(
+            front.iter().map(|guard| guard.id()).collect::<Vec<_>>(),
+            back_ids
+        );
+        assert_eq!(
+            back.iter().map(|guard| guard.id()).collect::<Vec<_>>(),
+            front_ids
+        );
+
+        assert_drop_stats!(registry, { created: 6, dropped: 0 });
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn swap_nonoverlapping_different_lengths() {
+        let mut values: Vec<_> = (0..3).collect();
+        let mut other_values: Vec<_> = (0..2).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let mut other = WriteOnlySlice::from(&mut other_values[..]);
+        slice.swap_nonoverlapping(&mut other);
+    }
+
+    #[test]
+    fn rotate_left() {
+        let registry = DropRegistry::default();
+        let (ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        {
+            let mut slice = WriteOnlySlice::from(&mut guards[..]);
+            slice.rotate_left(2);
+        }
+
+        let rotated_ids: Vec<_> = guards.iter().map(|guard| guard.id()).collect();
+        assert_eq!(rotated_ids, [ids[2], ids[3], ids[4], ids[0], ids[1]]);
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, [2, 3, 4, 0, 1]);
+
+        assert_drop_stats!(registry, { created: 5, dropped: 0 });
+    }
+
+    #[test]
+    fn rotate_right() {
+        let registry = DropRegistry::default();
+        let (ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
+
+        {
+            let mut slice = WriteOnlySlice::from(&mut guards[..]);
+            slice.rotate_right(2);
+            slice.rotate_right(0);
+            slice.rotate_left(5);
+        }
+
+        let rotated_ids: Vec<_> = guards.iter().map(|guard| guard.id()).collect();
+        assert_eq!(rotated_ids, [ids[3], ids[4], ids[0], ids[1], ids[2]]);
+
+        assert_drop_stats!(registry, { created: 5, dropped: 0 });
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for write-only slice of length 3")]
+    fn rotate_left_out_of_bounds() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.rotate_left(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_with_slice_different_lengths() {
+        let mut values: Vec<_> = (0..3).collect();
+        let mut other_values: Vec<_> = (0..2).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.swap_with_slice(&mut other_values[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "raw")]
+    fn from_non_null() {
+        let mut values = [0, 1, 2];
+
+        let mut slice =
+            unsafe { WriteOnlySlice::from_non_null(ptr::NonNull::from(&mut values).cast(), 3) };
+        slice.write_at(1, 42);
+
+        assert_eq!(values, [0, 42, 2]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "raw", debug_assertions))]
+    #[should_panic(expected = "attempt to create slice covering at least half the address space")]
+    fn from_raw_parts_mut_too_large() {
+        let data = ptr::NonNull::<u16>::dangling().as_ptr();
+
+        let _ = unsafe { WriteOnlySlice::from_raw_parts_mut(data, isize::MAX as usize) };
+    }
+
+    #[test]
+    #[cfg(all(feature = "raw", debug_assertions))]
+    #[should_panic(expected = "attempt to create unaligned or null slice")]
+    fn from_raw_parts_mut_unaligned() {
+        let mut values = [0_u32; 2];
+
+        // Aligned for `u16`, but not for `u32`.
+        let data = values
+            .as_mut_ptr()
+            .cast::<u16>()
+            .wrapping_add(1)
+            .cast::<u32>();
+
+        let _ = unsafe { WriteOnlySlice::from_raw_parts_mut(data, 1) };
+    }
+
+    #[test]
+    #[cfg(feature = "raw")]
+    fn from_unsafe_cell() {
+        let mut values = [0, 1, 2];
+
+        let cell: &std::cell::UnsafeCell<[i32]> = std::cell::UnsafeCell::from_mut(&mut values[..]);
+
+        let mut slice = unsafe { WriteOnlySlice::from_unsafe_cell(cell) };
+        assert_eq!(slice.len(), 3);
+        slice.put_at(2, 42);
+
+        assert_eq!(values, [0, 1, 42]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut values: Vec<_> = (0..4).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let mut iter = slice.into_iter();
+
+        assert_eq!(iter.len(), 4);
+
+        iter.next().unwrap().put(10);
+        iter.next_back().unwrap().put(13);
+
+        assert_eq!(iter.len(), 2);
+
+        for (index, mut reference) in iter.enumerate() {
+            reference.put(index + 20);
+        }
+
+        assert_eq!(values, &[10, 20, 21, 13]);
+    }
+
+    #[test]
+    fn split_at() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let (mut left, mut right) = slice.split_at(2);
+
+        assert_eq!((left.len(), right.len()), (2, 3));
+
+        left.put_at(1, 42);
+        right.put_at(0, 43);
+
+        assert_eq!(values, &[0, 42, 43, 3, 4]);
+    }
+
+    #[test]
+    fn split_at_ref() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        for mid in [1, 3] {
+            let (mut left, mut right) = slice.split_at_ref(mid);
+            left.put_at(mid - 1, 40 + mid);
+            right.put_at(0, 50 + mid);
+        }
+
+        slice.put_at(4, 42);
+
+        assert_eq!(values, &[41, 51, 43, 53, 42]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_ref_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let _ = slice.split_at_ref(6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let _ = slice.split_at(6);
+    }
+
+    #[test]
+    fn send() {
+        let mut values: Vec<_> = (0..4).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let (mut left, mut right) = slice.split_at(2);
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || left.put_at(0, 10));
+            scope.spawn(move || right.put_at(1, 13));
+        });
+
+        assert_eq!(values, &[10, 1, 2, 13]);
+    }
+
+    #[test]
+    fn put_at_into() {
+        let mut values: Vec<u64> = vec![0; 3];
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_at_into(0, 1u8);
+        slice.write_at_into(2, 3u32);
+
+        assert_eq!(values, &[1, 0, 3]);
+    }
+
+    #[test]
+    fn as_volatile() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.as_volatile().write_at(0, 10);
+        slice.put_at(2, 12);
+
+        assert_eq!(values, &[10, 1, 12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 100 out of bounds for write-only slice of length 5")]
+    fn put_cloning_from_slice_at_offset_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_cloning_from_slice_at(&[], 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for write-only slice of length 5")]
+    fn put_cloning_from_slice_at_offset_overflow() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_cloning_from_slice_at(&[1, 2], usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 100 out of bounds for write-only slice of length 5")]
+    fn put_copying_from_slice_at_offset_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_copying_from_slice_at(&[], 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for write-only slice of length 5")]
+    fn put_copying_from_slice_at_offset_overflow() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_copying_from_slice_at(&[1, 2], usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 100 out of bounds for write-only slice of length 5")]
+    fn write_cloning_from_slice_at_offset_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_cloning_from_slice_at(&[], 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for write-only slice of length 5")]
+    fn write_cloning_from_slice_at_offset_overflow() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_cloning_from_slice_at(&[1, 2], usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 100 out of bounds for write-only slice of length 5")]
+    fn write_copying_from_slice_at_offset_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_copying_from_slice_at(&[], 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for write-only slice of length 5")]
+    fn write_copying_from_slice_at_offset_overflow() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_copying_from_slice_at(&[1, 2], usize::MAX);
+    }
+
+    #[test]
+    fn split_first_chunk() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let (mut head, mut tail) = slice.split_first_chunk::<2>().unwrap();
+        head.put([10, 11]);
+        tail.write_at(0, 12);
+
+        assert_eq!(tail.len(), 3);
+        assert_eq!(values, &[10, 11, 12, 3, 4]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    fn put_at_unchecked_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        unsafe { slice.put_at_unchecked(5, 42) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    fn write_at_unchecked_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        unsafe { slice.write_at_unchecked(5, 42) };
+    }
+
+    #[test]
+    fn put_single() {
+        fn put_into<P: Put<u32>>(mut sink: P, value: u32) {
+            sink.put(value);
+        }
+
+        let mut values = [0_u32; 3];
+
+        let slice = WriteOnlySlice::from(&mut values);
+        let (_, tail) = slice.split_at(2);
+        put_into(tail, 42);
+
+        assert_eq!(values, [0, 0, 42]);
+    }
+
+    #[test]
+    fn write_single() {
+        let mut values = [0_u32; 1];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        Write::write(&mut slice, 42);
+
+        assert_eq!(values, [42]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a slice of length 1")]
+    fn put_single_wrong_length() {
+        let mut values = [0_u32; 2];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        Put::put(&mut slice, 42);
+    }
+
+    #[test]
+    fn take() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let mut head = slice.take(2);
+        let mut next = slice.take(1);
+        head.write_copying_from_slice_at(&[10, 11], 0);
+        next.write_at(0, 12);
+        slice.write_at(0, 13);
+
+        assert_eq!(slice.len(), 2);
+        assert_eq!(values, &[10, 11, 12, 13, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 6 out of bounds for write-only slice of length 5")]
+    fn take_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let _ = slice.take(6);
+    }
+
+    #[test]
+    fn split_first_chunk_too_short() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.split_first_chunk::<6>().is_none());
+    }
+
+    #[test]
+    fn put_all_from_iter() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_all_from_iter(10..13);
+
+        assert_eq!(values, &[10, 11, 12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer")]
+    fn put_all_from_iter_too_few() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_all_from_iter(10..12);
+    }
+
+    #[test]
+    #[should_panic(expected = "more")]
+    fn put_all_from_iter_too_many() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_all_from_iter(10..14);
+    }
+
+    #[test]
+    fn write_all_from_iter() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_all_from_iter(10..13);
+
+        assert_eq!(values, &[10, 11, 12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer")]
+    fn write_all_from_iter_too_few() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_all_from_iter(10..12);
+    }
+
+    #[test]
+    #[should_panic(expected = "more")]
+    fn write_all_from_iter_too_many() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_all_from_iter(10..14);
+    }
+
+    #[test]
+    fn flatten() {
+        let mut pixels: Vec<[u8; 4]> = vec![[0; 4]; 2];
+
+        let slice = WriteOnlySlice::from(&mut pixels[..]);
+        let mut flat = slice.flatten();
+
+        assert_eq!(flat.len(), 8);
+
+        flat.put_at(5, 42);
+
+        assert_eq!(pixels, &[[0, 0, 0, 0], [0, 42, 0, 0]]);
+    }
+
+    #[test]
+    fn unflatten() {
+        let mut values: Vec<u8> = vec![0; 8];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let mut pixels = slice.unflatten::<4>().unwrap();
+
+        assert_eq!(pixels.len(), 2);
+
+        pixels.put_at(1, [1, 2, 3, 4]);
+
+        assert_eq!(values, &[0, 0, 0, 0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn as_chunks() {
+        let mut values: Vec<u8> = vec![0; 7];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let (mut chunks, mut remainder) = slice.as_chunks::<3>();
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(remainder.len(), 1);
+
+        chunks.put_at(1, [1, 2, 3]);
+        remainder.put_at(0, 4);
+
+        assert_eq!(values, &[0, 0, 0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn as_rchunks() {
+        let mut values: Vec<u8> = vec![0; 7];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let (mut remainder, mut chunks) = slice.as_rchunks::<3>();
+
+        assert_eq!(remainder.len(), 1);
+        assert_eq!(chunks.len(), 2);
+
+        remainder.put_at(0, 4);
+        chunks.put_at(0, [1, 2, 3]);
+
+        assert_eq!(values, &[4, 1, 2, 3, 0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn as_chunks_zero() {
+        let mut values: Vec<u8> = vec![0; 7];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+        let _ = slice.as_chunks::<0>();
+    }
+
+    #[test]
+    fn unflatten_uneven() {
+        let mut values: Vec<u8> = vec![0; 7];
+
+        let slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.unflatten::<4>().is_none());
+    }
+
+    #[test]
+    fn set_slice() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.set_slice(1..3, &[10, 11]);
+
+        assert_eq!(values, &[0, 10, 11, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn set_slice_length_mismatch() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.set_slice(1..3, &[10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    fn set_slice_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.set_slice(4..6, &[10, 11]);
+    }
+
+    #[test]
+    fn put_slice() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let new_guards: Vec<_> = (3..5).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_slice(1..3, &new_guards[..]);
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 3, 4]);
+
+        assert_no_drop!(registry, old_ids[0]);
         assert_drop!(registry, old_ids[1]);
         assert_drop!(registry, old_ids[2]);
-        assert_drop!(registry, old_ids[3]);
-        assert_drop_stats!(registry, { created: 11, dropped: 3 });
+        assert_drop_stats!(registry, { created: 7, dropped: 2 });
     }
 
     #[test]
-    fn write_cloning_from_slice_at() {
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn put_slice_length_mismatch() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_slice(1..3, &[10]);
+    }
+
+    #[test]
+    fn copy_from_slice() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.copy_from_slice(&[3, 4, 5]);
+
+        assert_eq!(values, &[3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn copy_from_slice_length_mismatch() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.copy_from_slice(&[3, 4]);
+    }
+
+    #[test]
+    fn clone_from_slice() {
         let registry = DropRegistry::default();
         let (old_ids, mut guards): (Vec<_>, Vec<_>) =
-            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
+            (0..2).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let new_guards: Vec<_> = (2..4).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.clone_from_slice(&new_guards[..]);
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[2, 3]);
+
+        assert_drop!(registry, old_ids[0]);
+        assert_drop!(registry, old_ids[1]);
+        assert_drop_stats!(registry, { created: 6, dropped: 2 });
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn clone_from_slice_length_mismatch() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.clone_from_slice(&[3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn try_put_at() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.try_put_at(2, 42), Ok(()));
+        assert_eq!(
+            slice.try_put_at(3, 43),
+            Err(CapacityError {
+                needed: 4,
+                available: 3
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 42]);
+    }
+
+    #[test]
+    fn try_write_at() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.try_write_at(2, 42), Ok(()));
+        assert_eq!(
+            slice.try_write_at(3, 43),
+            Err(CapacityError {
+                needed: 4,
+                available: 3
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 42]);
+    }
+
+    #[test]
+    fn put_cloning_from_slice_at_counted() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..5).map(|i| registry.new_guard_for(i)).collect();
         let new_guards: Vec<_> = (5..8).map(|i| registry.new_guard_for(i)).collect();
 
         let mut slice = WriteOnlySlice::from(&mut guards[..]);
-        slice.write_cloning_from_slice_at(&new_guards[..], 1);
+        let count = slice.put_cloning_from_slice_at_counted(&new_guards, 2);
 
-        assert_ne!(guards[1].id(), old_ids[1]);
-        assert_eq!(guards[1].value(), &5);
-        assert_ne!(guards[2].id(), old_ids[1]);
-        assert_eq!(guards[2].value(), &6);
-        assert_ne!(guards[3].id(), old_ids[2]);
-        assert_eq!(guards[3].value(), &7);
+        assert_eq!(count, 3);
+        assert_drop_stats!(registry, { created: 11, dropped: 3 });
+    }
 
-        assert_no_drop!(registry, old_ids[1]);
-        assert_no_drop!(registry, old_ids[2]);
-        assert_no_drop!(registry, old_ids[3]);
-        assert_drop_stats!(registry, { created: 11, dropped: 0 });
+    #[test]
+    fn put_mapped_from_slice_at() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..5).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_mapped_from_slice_at(&[5, 6], 3, |&i| registry.new_guard_for(i));
+
+        assert_drop_stats!(registry, { created: 7, dropped: 2 });
+        assert_eq!(guards[3].value(), &5);
+        assert_eq!(guards[4].value(), &6);
     }
 
     #[test]
-    fn write_copying_from_slice_at() {
+    fn write_mapped_from_slice_at() {
+        let samples = [0.5_f32, -1.0, 1.0];
+        let mut values = [0_i16; 4];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        slice.write_mapped_from_slice_at(&samples, 1, |&sample| {
+            (sample * f32::from(i16::MAX)) as i16
+        });
+
+        assert_eq!(values, [0, 16383, -32767, 32767]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for write-only slice of length 4")]
+    fn write_mapped_from_slice_at_out_of_bounds() {
+        let mut values = [0_u8; 4];
+
+        let mut slice = WriteOnlySlice::from(&mut values);
+        slice.write_mapped_from_slice_at(&[1, 2], 3, |&value| value);
+    }
+
+    #[test]
+    fn try_put_cloning_from_slice_at() {
         let mut values: Vec<_> = (0..5).collect();
-        let new_values: Vec<_> = (5..8).collect();
 
         let mut slice = WriteOnlySlice::from(&mut values[..]);
-        slice.write_copying_from_slice_at(&new_values[..], 1);
 
-        assert_eq!(values, &[0, 5, 6, 7, 4]);
+        assert_eq!(slice.try_put_cloning_from_slice_at(&[5, 6], 3), Ok(()));
+        assert_eq!(
+            slice.try_put_cloning_from_slice_at(&[7, 8], 4),
+            Err(CapacityError {
+                needed: 6,
+                available: 5
+            })
+        );
+        assert_eq!(
+            slice.try_put_cloning_from_slice_at(&[], usize::MAX),
+            Err(CapacityError {
+                needed: usize::MAX,
+                available: 5
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn try_write_cloning_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.try_write_cloning_from_slice_at(&[5, 6], 3), Ok(()));
+        assert_eq!(
+            slice.try_write_cloning_from_slice_at(&[7, 8], 4),
+            Err(CapacityError {
+                needed: 6,
+                available: 5
+            })
+        );
+        assert_eq!(
+            slice.try_write_cloning_from_slice_at(&[], usize::MAX),
+            Err(CapacityError {
+                needed: usize::MAX,
+                available: 5
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn write_str_at() {
+        let mut bytes = [0_u8; 8];
+
+        let mut slice = WriteOnlySlice::from(&mut bytes);
+
+        assert_eq!(slice.write_str_at(1, "héllo"), 6);
+
+        assert_eq!(&bytes, b"\0h\xc3\xa9llo\0");
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for write-only slice of length 4")]
+    fn write_str_at_out_of_bounds() {
+        let mut bytes = [0_u8; 4];
+
+        let mut slice = WriteOnlySlice::from(&mut bytes);
+        slice.write_str_at(1, "abcd");
+    }
+
+    #[test]
+    fn try_write_str_at() {
+        let mut bytes = [0_u8; 4];
+
+        let mut slice = WriteOnlySlice::from(&mut bytes);
+
+        assert_eq!(slice.try_write_str_at(1, "abc"), Ok(3));
+        assert_eq!(
+            slice.try_write_str_at(2, "xyz"),
+            Err(CapacityError {
+                needed: 5,
+                available: 4
+            })
+        );
+
+        assert_eq!(&bytes, b"\0abc");
+    }
+
+    #[test]
+    fn try_write_copying_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.try_write_copying_from_slice_at(&[5, 6], 3), Ok(()));
+        assert_eq!(
+            slice.try_write_copying_from_slice_at(&[7, 8], 4),
+            Err(CapacityError {
+                needed: 6,
+                available: 5
+            })
+        );
+        assert_eq!(
+            slice.try_write_copying_from_slice_at(&[], usize::MAX),
+            Err(CapacityError {
+                needed: usize::MAX,
+                available: 5
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn put_strided_from_slice_at() {
+        let mut values: Vec<_> = (0..7).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_strided_from_slice_at(&[10, 11, 12], 1, 2);
+
+        assert_eq!(values, &[0, 10, 2, 11, 4, 12, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 7 out of bounds for write-only slice of length 7")]
+    fn put_strided_from_slice_at_out_of_bounds() {
+        let mut values: Vec<_> = (0..7).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_strided_from_slice_at(&[10, 11, 12], 1, 3);
+    }
+
+    #[test]
+    fn write_strided_from_slice_at() {
+        let mut values: Vec<_> = (0..7).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_strided_from_slice_at(&[10, 11, 12], 1, 2);
+
+        assert_eq!(values, &[0, 10, 2, 11, 4, 12, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 7 out of bounds for write-only slice of length 7")]
+    fn write_strided_from_slice_at_out_of_bounds() {
+        let mut values: Vec<_> = (0..7).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_strided_from_slice_at(&[10, 11, 12], 1, 3);
+    }
+
+    #[test]
+    fn put_default() {
+        let mut values: Vec<String> = vec!["a".to_owned(), "b".to_owned()];
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.put_default();
+
+        assert_eq!(values, &["", ""]);
+    }
+
+    #[test]
+    fn write_default() {
+        let mut values: Vec<u32> = vec![1, 2];
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_default();
+
+        assert_eq!(values, &[0, 0]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn assume_readable() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        slice.write_at(1, 42);
+
+        let readable = unsafe { slice.assume_readable() };
+
+        assert_eq!(readable, &[0, 42, 2]);
+    }
+
+    #[test]
+    fn halve() {
+        let mut even: Vec<_> = (0..4).collect();
+        let mut odd: Vec<_> = (0..5).collect();
+
+        let (left, right) = WriteOnlySlice::from(&mut even[..]).halve();
+
+        assert_eq!((left.len(), right.len()), (2, 2));
+
+        let (left, right) = WriteOnlySlice::from(&mut odd[..]).halve();
+
+        assert_eq!((left.len(), right.len()), (3, 2));
+    }
+
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    fn drop_does_not_drop_elements() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut slice = WriteOnlySlice::from(&mut guards[..]);
+        slice.put_at(1, new_guard);
+
+        std::mem::drop(slice);
+
+        assert_drop!(registry, old_ids[1]);
+        assert_no_drop!(registry, new_id);
+        assert_drop_stats!(registry, { created: 4, dropped: 1 });
+
+        std::mem::drop(guards);
+
+        assert_drop_stats!(registry, { created: 4, dropped: 4 });
     }
 }
@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Debug-build detection of overlapping write-only slices formed from raw pointers.
+
+use std::{ops::Range, sync::Mutex, vec::Vec};
+
+/// The address ranges of all live write-only slices formed from raw pointers.
+///
+/// The registry is global rather than thread-local, as write-only slices are `Send`.
+static LIVE: Mutex<Vec<Range<usize>>> = Mutex::new(Vec::new());
+
+/// Keeps the address range of a write-only slice registered for as long as it is alive.
+#[derive(Debug)]
+pub(crate) struct OverlapGuard {
+    range: Range<usize>,
+}
+
+impl OverlapGuard {
+    /// Registers the address range of `len` elements starting at `data`.
+    ///
+    /// Returns `None` for empty ranges, as they cannot overlap with anything.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range overlaps with that of another live registered slice.
+    #[track_caller]
+    pub(crate) fn register<T>(data: *mut T, len: usize) -> Option<Self> {
+        let start = data.addr();
+        let end = start.saturating_add(len.saturating_mul(std::mem::size_of::<T>()));
+
+        if start == end {
+            return None;
+        }
+
+        let mut live = LIVE.lock().unwrap_or_else(|error| error.into_inner());
+
+        if let Some(other) = live
+            .iter()
+            .find(|other| other.start < end && start < other.end)
+        {
+            let other = other.clone();
+            drop(live);
+            panic!(
+                "write-only slice {start:#x}..{end:#x} overlaps live write-only slice {:#x}..{:#x}",
+                other.start, other.end
+            );
+        }
+
+        live.push(start..end);
+
+        Some(Self { range: start..end })
+    }
+}
+
+impl Drop for OverlapGuard {
+    fn drop(&mut self) {
+        let mut live = LIVE.lock().unwrap_or_else(|error| error.into_inner());
+
+        if let Some(index) = live.iter().position(|range| *range == self.range) {
+            live.swap_remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{VolatileWriteOnlySlice, WriteAt, WriteOnlySlice};
+
+    #[test]
+    fn disjoint() {
+        let mut values = [0_u32; 4];
+        let data = values.as_mut_ptr();
+
+        let mut left = unsafe { WriteOnlySlice::from_raw_parts_mut(data, 2) };
+        let mut right =
+            unsafe { VolatileWriteOnlySlice::from_raw_parts_mut(data.wrapping_add(2), 2) };
+        let _empty = unsafe { WriteOnlySlice::from_raw_parts_mut(data, 0) };
+
+        left.write_at(1, 1);
+        right.write_at(0, 2);
+
+        drop((left, right));
+
+        assert_eq!(values, [0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn reregister_after_drop() {
+        let mut values = [0_u32; 4];
+        let data = values.as_mut_ptr();
+
+        let slice = unsafe { WriteOnlySlice::from_raw_parts_mut(data, 4) };
+        drop(slice);
+
+        let mut slice = unsafe { WriteOnlySlice::from_raw_parts_mut(data, 4) };
+        slice.write_at(3, 3);
+
+        assert_eq!(values, [0, 0, 0, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "overlaps live write-only slice")]
+    fn overlapping() {
+        let mut values = [0_u32; 4];
+        let data = values.as_mut_ptr();
+
+        let _left = unsafe { WriteOnlySlice::from_raw_parts_mut(data, 3) };
+        let _right = unsafe { VolatileWriteOnlySlice::from_raw_parts_mut(data.wrapping_add(2), 2) };
+    }
+}
@@ -0,0 +1,220 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ptr,
+};
+
+use crate::WriteOnlySlice;
+
+/// A write-only **reference** to a whole **slice** with **dropping non-volatile** write access.
+///
+/// Unlike the index-oriented [`WriteOnlySlice`] the reference only ever
+/// replaces the slice's contents as a whole:
+///
+/// ```
+/// use write_only::WriteOnlySliceRef;
+///
+/// let mut values = [0_u8; 3];
+///
+/// let mut reference = WriteOnlySliceRef::from(&mut values[..]);
+/// reference.copy_from(&[1, 2, 3]);
+///
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+///
+/// # Drop semantics
+///
+/// The reference borrows its elements rather than owning them:
+/// dropping the reference itself never drops any of its elements.
+/// Only the old values replaced by [`WriteOnlySliceRef::clone_from`] get dropped.
+pub struct WriteOnlySliceRef<'a, T: 'a> {
+    data: *mut [T],
+    _phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T: 'a> WriteOnlySliceRef<'a, T> {
+    /// Returns the number of elements in the referenced slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the referenced slice has a length of 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.len() == 0
+    }
+
+    /// Copies all elements from `src` into the referenced slice, using a memcpy.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    #[inline]
+    #[track_caller]
+    pub fn copy_from(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        self.as_write_only_slice().copy_from_slice(src);
+    }
+
+    /// Clones all elements from `src` into the referenced slice, dropping the old values.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the two slices have different lengths.
+    #[inline]
+    #[track_caller]
+    pub fn clone_from(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        self.as_write_only_slice().clone_from_slice(src);
+    }
+
+    #[inline]
+    fn as_write_only_slice(&mut self) -> WriteOnlySlice<'_, T> {
+        // SAFETY: `self.data` is valid for writes of `self.len()` elements by definition.
+        unsafe { WriteOnlySlice::new_unchecked(self.data as *mut T, self.data.len()) }
+    }
+}
+
+impl<'a, T: 'a> From<&'a mut [T]> for WriteOnlySliceRef<'a, T> {
+    #[inline]
+    fn from(slice: &'a mut [T]) -> Self {
+        Self {
+            data: slice,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> From<&'a mut [T; N]> for WriteOnlySliceRef<'a, T> {
+    #[inline]
+    fn from(array: &'a mut [T; N]) -> Self {
+        Self::from(&mut array[..])
+    }
+}
+
+// SAFETY: a `WriteOnlySliceRef` is only ever created from a `&'a mut [T]`, so sending it
+// to another thread moves the exclusive right to replace (and, via `clone_from`, to drop)
+// the slice's elements there, exactly as sending the `&'a mut [T]` would.
+unsafe impl<'a, T: 'a> Send for WriteOnlySliceRef<'a, T> where T: Send {}
+
+// SAFETY: `copy_from` and `clone_from` take `&mut self`, so a shared `&WriteOnlySliceRef`
+// merely allows querying the length and comparing and hashing the pointer.
+unsafe impl<'a, T: 'a> Sync for WriteOnlySliceRef<'a, T> where T: Sync {}
+
+/// Compares by identity (i.e. same base pointer and length), not by value.
+impl<'a, T: 'a> PartialEq for WriteOnlySliceRef<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data)
+    }
+}
+
+impl<'a, T: 'a> Eq for WriteOnlySliceRef<'a, T> {}
+
+/// Hashes by identity (i.e. by base pointer and length), not by value.
+impl<'a, T: 'a> Hash for WriteOnlySliceRef<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.data as *mut T).hash(state);
+        self.data.len().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use droptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn len() {
+        let mut values = [0_u8; 3];
+        let reference = WriteOnlySliceRef::from(&mut values);
+        assert_eq!(reference.len(), 3);
+        assert!(!reference.is_empty());
+
+        let mut values: [u8; 0] = [];
+        let reference = WriteOnlySliceRef::from(&mut values);
+        assert!(reference.is_empty());
+    }
+
+    #[test]
+    fn copy_from() {
+        let mut values: Vec<u32> = vec![0; 4];
+
+        {
+            let mut reference = WriteOnlySliceRef::from(&mut values[..]);
+            reference.copy_from(&[1, 2, 3, 4]);
+        }
+
+        assert_eq!(values, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn copy_from_different_lengths() {
+        let mut values: Vec<u32> = vec![0; 4];
+
+        let mut reference = WriteOnlySliceRef::from(&mut values[..]);
+        reference.copy_from(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn clone_from() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let new_guards: Vec<_> = (3..6).map(|i| registry.new_guard_for(i)).collect();
+
+        {
+            let mut reference = WriteOnlySliceRef::from(&mut guards[..]);
+            reference.clone_from(&new_guards);
+        }
+
+        for old_id in old_ids {
+            assert_drop!(registry, old_id);
+        }
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, [3, 4, 5]);
+
+        assert_drop_stats!(registry, { created: 9, dropped: 3 });
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn clone_from_different_lengths() {
+        let mut values: Vec<String> = vec![String::new(); 2];
+
+        let mut reference = WriteOnlySliceRef::from(&mut values[..]);
+        reference.clone_from(&["a".to_owned()]);
+    }
+
+    #[test]
+    fn eq() {
+        let mut values = [0_u8; 4];
+        let data = values.as_mut_ptr();
+
+        let whole = WriteOnlySliceRef::from(&mut values);
+        let alias = WriteOnlySliceRef::<u8> {
+            data: ptr::slice_from_raw_parts_mut(data, 4),
+            _phantom: PhantomData,
+        };
+        let prefix = WriteOnlySliceRef::<u8> {
+            data: ptr::slice_from_raw_parts_mut(data, 2),
+            _phantom: PhantomData,
+        };
+
+        assert!(whole == alias);
+        assert!(whole != prefix);
+    }
+}
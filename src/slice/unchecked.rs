@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::marker::PhantomData;
+
+/// A writer with **non-dropping non-volatile** write access to a slice's elements,
+/// performing **no bounds checks** (other than in debug builds).
+///
+/// Obtained via [`WriteOnlySlice::writer_unchecked`](crate::WriteOnlySlice::writer_unchecked),
+/// whose caller promises to only ever write to in-bounds indices.
+pub struct UncheckedWriter<'a, T: 'a> {
+    data: *mut T,
+    #[cfg(debug_assertions)]
+    len: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> UncheckedWriter<'a, T> {
+    /// Forms a writer from a pointer and a length.
+    ///
+    /// # Safety
+    ///
+    /// Same as for `WriteOnlySlice::from_raw_parts_mut`, and the caller
+    /// must only ever write to indices less than `len` via the writer.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub(crate) unsafe fn new_unchecked(data: *mut T, len: usize) -> Self {
+        #[cfg(not(debug_assertions))]
+        let _ = len;
+
+        Self {
+            data,
+            #[cfg(debug_assertions)]
+            len,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Performs a write of a memory location with the given value without reading or dropping the old value,
+    /// and without checking `index` against the slice's bounds.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds this function will panic if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn write(&mut self, index: usize, value: T) {
+        #[cfg(debug_assertions)]
+        super::debug_assert_index_in_bounds::<T>(index, self.len);
+
+        // SAFETY: the caller of `WriteOnlySlice::writer_unchecked`
+        // promised to only ever write to in-bounds indices.
+        unsafe {
+            self.data.add(index).write(value);
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "raw", not(feature = "safe"))))]
+mod tests {
+    use crate::WriteOnlySlice;
+
+    #[test]
+    fn write() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let mut writer = unsafe { slice.writer_unchecked() };
+
+        for index in 0..5 {
+            writer.write(index, index * 10);
+        }
+
+        assert_eq!(values, &[0, 10, 20, 30, 40]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    fn write_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = WriteOnlySlice::from(&mut values[..]);
+        let mut writer = unsafe { slice.writer_unchecked() };
+        writer.write(5, 50);
+    }
+}
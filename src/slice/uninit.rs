@@ -0,0 +1,177 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ptr,
+};
+
+use crate::WriteAt;
+
+/// A write-only **slice** with **non-dropping non-volatile** write access to **uninitialized** values.
+///
+/// In debug builds (with the `std` feature enabled) the slice keeps track of which
+/// of its elements have been written to, allowing [`UninitWriteOnlySlice::into_init`]
+/// to catch missing writes.
+#[must_use = "an uninitialized slice should be converted via `into_init` once written"]
+pub struct UninitWriteOnlySlice<'a, T: 'a> {
+    data: *mut T,
+    len: usize,
+    #[cfg(all(debug_assertions, feature = "std"))]
+    written: Vec<bool>,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> UninitWriteOnlySlice<'a, T> {
+    /// Forms a write-only slice from a slice of uninitialized values.
+    #[inline]
+    pub fn from_uninit(slice: &'a mut [MaybeUninit<T>]) -> Self {
+        Self {
+            data: slice.as_mut_ptr() as *mut T,
+            len: slice.len(),
+            #[cfg(all(debug_assertions, feature = "std"))]
+            written: vec![false; slice.len()],
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slice has a length of 0.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Converts the slice into a slice of initialized values.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds (with the `std` feature enabled) this function
+    /// will panic if any of the slice's elements have not been written to.
+    ///
+    /// # Safety
+    ///
+    /// Every element of the slice must have been written to.
+    /// Calling this when the content is not yet fully initialized
+    /// causes immediate undefined behavior.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn into_init(self) -> &'a mut [T] {
+        #[cfg(all(debug_assertions, feature = "std"))]
+        if let Some(index) = self.written.iter().position(|written| !written) {
+            panic!("attempt to assume uninitialized element at index {index} to be initialized");
+        }
+
+        // SAFETY: the caller must uphold the safety contract for `into_init`.
+        core::slice::from_raw_parts_mut(self.data, self.len)
+    }
+}
+
+impl<'a, T: 'a> WriteAt<T> for UninitWriteOnlySlice<'a, T> {
+    #[inline]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        super::assert_index_in_bounds(index, self.len);
+
+        unsafe {
+            self.write_at_unchecked(index, value);
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        super::debug_assert_index_in_bounds::<T>(index, self.len);
+
+        #[cfg(all(debug_assertions, feature = "std"))]
+        {
+            self.written[index] = true;
+        }
+
+        self.data.add(index).write(value);
+    }
+}
+
+/// Compares by identity (i.e. same base pointer and length), not by value.
+impl<'a, T: 'a> PartialEq for UninitWriteOnlySlice<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data) && self.len == other.len
+    }
+}
+
+impl<'a, T: 'a> Eq for UninitWriteOnlySlice<'a, T> {}
+
+/// Hashes by identity (i.e. by base pointer and length), not by value.
+impl<'a, T: 'a> Hash for UninitWriteOnlySlice<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.len.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    use droptest::prelude::*;
+
+    #[test]
+    fn from_uninit() {
+        let mut values: [MaybeUninit<u8>; 3] = [MaybeUninit::uninit(); 3];
+
+        let slice = UninitWriteOnlySlice::from_uninit(&mut values);
+
+        assert_eq!(slice.len(), 3);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn into_init() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<MaybeUninit<DropGuard<i32>>> =
+            (0..3).map(|_| MaybeUninit::uninit()).collect();
+
+        let mut slice = UninitWriteOnlySlice::from_uninit(&mut guards[..]);
+        for index in 0..3 {
+            slice.write_at(index, registry.new_guard_for(index as i32));
+        }
+        let init = unsafe { slice.into_init() };
+
+        let values: Vec<_> = init.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 1, 2]);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+
+        for guard in init.iter_mut() {
+            unsafe { core::ptr::drop_in_place(guard) };
+        }
+
+        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index 1")]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn into_init_partially_written() {
+        let mut values: [MaybeUninit<u8>; 3] = [MaybeUninit::uninit(); 3];
+
+        let mut slice = UninitWriteOnlySlice::from_uninit(&mut values);
+        slice.write_at(0, 1);
+        slice.write_at(2, 3);
+
+        let _ = unsafe { slice.into_init() };
+    }
+}
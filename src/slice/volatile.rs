@@ -2,15 +2,69 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::{marker::PhantomData, mem, ptr};
+use core::{
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+    ops::Range,
+    ptr,
+    sync::atomic::{compiler_fence, Ordering},
+};
 
-use crate::{WriteAt, WriteFromSliceAt};
+use crate::{CapacityError, VolatileWriteOnlyRef, WriteAt, WriteFromSliceAt, WriteOnlySlice};
 
 /// A write-only **slice** with **non-dropping volatile** write access.
+///
+/// All writes, including bulk writes via [`WriteFromSliceAt`],
+/// are performed as one volatile store per element, never as a `memcpy`.
+///
+/// # Drop semantics
+///
+/// The slice borrows its elements rather than owning them:
+/// dropping the slice itself never drops any of its elements.
+/// Neither are the old values replaced by its writes ever dropped.
+///
+/// # Variance
+///
+/// Just like `&'a mut [T]` the slice is covariant in `'a`, but invariant in `T`.
+///
+/// Shortening `'a` is allowed:
+///
+/// ```
+/// use write_only::VolatileWriteOnlySlice;
+///
+/// fn shorten<'long: 'short, 'short, T>(slice: VolatileWriteOnlySlice<'long, T>) -> VolatileWriteOnlySlice<'short, T> {
+///     slice
+/// }
+/// ```
+///
+/// Shortening the lifetimes within `T` is rejected,
+/// as otherwise shorter-lived values could be written into longer-lived storage:
+///
+/// ```compile_fail
+/// use write_only::VolatileWriteOnlySlice;
+///
+/// fn shorten<'a, 'short>(slice: VolatileWriteOnlySlice<'a, &'static str>) -> VolatileWriteOnlySlice<'a, &'short str> {
+///     slice
+/// }
+/// ```
+///
+/// Just as is extending them:
+///
+/// ```compile_fail
+/// use write_only::VolatileWriteOnlySlice;
+///
+/// fn extend<'a, 'short>(slice: VolatileWriteOnlySlice<'a, &'short str>) -> VolatileWriteOnlySlice<'a, &'static str> {
+///     slice
+/// }
+/// ```
 pub struct VolatileWriteOnlySlice<'a, T: 'a> {
     data: *mut T,
     len: usize,
-    _phantom: PhantomData<&'a T>,
+    _phantom: PhantomData<&'a mut T>,
+    #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+    _overlap: Option<super::overlap::OverlapGuard>,
 }
 
 impl<'a, T: 'a> VolatileWriteOnlySlice<'a, T> {
@@ -50,206 +104,1541 @@ impl<'a, T: 'a> VolatileWriteOnlySlice<'a, T> {
     /// source lifetime is safe in the context, such as by providing a helper
     /// function taking the lifetime of a host value for the slice, or by explicit
     /// annotation.
+    #[cfg(feature = "raw")]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn from_raw_parts_mut(data: *mut T, len: usize) -> Self {
+        let slice = Self::new_unchecked(data, len);
+
+        // Register the slice's range, panicking if it overlaps with another such slice.
+        #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+        let slice = Self {
+            _overlap: super::overlap::OverlapGuard::register(data, len),
+            ..slice
+        };
+
+        slice
+    }
+
+    /// Forms a write-only slice from a pointer and a length.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::from_raw_parts_mut`].
+    #[cfg(feature = "raw")]
+    #[deprecated(note = "renamed to `from_raw_parts_mut`")]
     #[inline]
     pub unsafe fn from_raw_parts(data: *mut T, len: usize) -> Self {
+        Self::from_raw_parts_mut(data, len)
+    }
+
+    /// Forms a write-only slice from a non-null pointer and a length.
+    ///
+    /// The `len` argument is the number of **elements**, not the number of bytes.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::from_raw_parts_mut`], with `data` being non-null guaranteed by its type.
+    #[cfg(feature = "raw")]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn from_non_null(data: ptr::NonNull<T>, len: usize) -> Self {
+        let slice = Self::new_unchecked(data.as_ptr(), len);
+
+        // Register the slice's range, panicking if it overlaps with another such slice.
+        #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+        let slice = Self {
+            _overlap: super::overlap::OverlapGuard::register(data.as_ptr(), len),
+            ..slice
+        };
+
+        slice
+    }
+
+    /// Forms a write-only slice from a pointer and a length.
+    ///
+    /// # Safety
+    ///
+    /// Same as for `from_raw_parts_mut`.
+    #[inline]
+    pub(crate) unsafe fn new_unchecked(data: *mut T, len: usize) -> Self {
         debug_assert!(
-            !data.is_null() && (data.align_offset(mem::align_of::<u16>()) == 0),
+            !data.is_null() && (data.align_offset(mem::align_of::<T>()) == 0),
             "attempt to create unaligned or null slice"
         );
         debug_assert!(
             mem::size_of::<T>().saturating_mul(len) <= isize::MAX as usize,
             "attempt to create slice covering at least half the address space"
         );
-        // SAFETY: the caller must uphold the safety contract for `from_raw_parts`.
+        // SAFETY: the caller must uphold the safety contract for `new_unchecked`.
         Self {
             data,
             len,
             _phantom: PhantomData,
+            #[cfg(all(feature = "debug-overlap-check", debug_assertions))]
+            _overlap: None,
         }
     }
 
+    /// Returns the number of elements in the slice.
     #[inline]
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns `true` if the slice has a length of 0.
     #[inline]
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
-}
 
-impl<'a, T: 'a> WriteAt<T> for VolatileWriteOnlySlice<'a, T> {
+    /// Returns the size of the slice in bytes, saturating at `usize::MAX`.
     #[inline]
-    fn write_at(&mut self, index: usize, value: T) {
-        assert!(index < self.len);
+    pub fn byte_len(&self) -> usize {
+        self.len.saturating_mul(mem::size_of::<T>())
+    }
 
-        unsafe {
-            self.write_at_unchecked(index, value);
-        }
+    /// Returns the size of an individual element of the slice in bytes.
+    #[inline]
+    pub fn element_size(&self) -> usize {
+        mem::size_of::<T>()
     }
 
+    /// Returns write-only references to many indices at once.
+    ///
+    /// Returns `None` if any index is out-of-bounds, or if the same index was passed more than once.
     #[inline]
-    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
-        self.data.add(index).write_volatile(value);
+    pub fn get_many<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[VolatileWriteOnlyRef<'_, T>; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.len || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        // SAFETY: all indices were checked to be in-bounds and pairwise distinct,
+        // so the returned references cannot alias.
+        Some(core::array::from_fn(|i| unsafe {
+            VolatileWriteOnlyRef::new_unchecked(self.data.add(indices[i]))
+        }))
     }
-}
 
-impl<'a, T: 'a> WriteFromSliceAt<T> for VolatileWriteOnlySlice<'a, T> {
+    /// Writes the value returned by `f(index)` at each `index` of the slice,
+    /// without reading or dropping the old values.
     #[inline]
-    fn write_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    pub fn write_indexed_with<F>(&mut self, mut f: F)
     where
-        T: Clone,
+        F: FnMut(usize) -> T,
     {
-        assert!(offset + src.len() <= self.len);
+        for index in 0..self.len {
+            unsafe {
+                self.write_at_unchecked(index, f(index));
+            }
+        }
+    }
 
-        // SAFETY: `self` is valid for `self.len()` elements by definition,
-        // and `src` was checked to have a length less than `self.len() - offset`.
-        // The slices cannot overlap because mutable references are exclusive.
+    /// Returns an iterator over `chunk_size` elements of the slice at a time,
+    /// starting at the beginning of the slice, borrowing rather than consuming `self`.
+    ///
+    /// The chunks are write-only slices and do not overlap. If `chunk_size` does not
+    /// divide the length of the slice, then the last chunk will not have length `chunk_size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    #[inline]
+    #[track_caller]
+    pub fn chunks_ref(
+        &mut self,
+        chunk_size: usize,
+    ) -> impl Iterator<Item = VolatileWriteOnlySlice<'_, T>> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let data = self.data;
+        let len = self.len;
+
+        // SAFETY: each chunk covers a distinct in-bounds sub-range of `self`,
+        // which stays mutably borrowed for as long as the chunks are alive.
+        (0..len).step_by(chunk_size).map(move |start| unsafe {
+            VolatileWriteOnlySlice::new_unchecked(data.add(start), chunk_size.min(len - start))
+        })
+    }
+
+    /// Returns an iterator yielding a write-only reference with **non-dropping volatile**
+    /// write access per element, borrowing rather than consuming `self`.
+    ///
+    /// Each reference performs its own distinct volatile store,
+    /// e.g. for driving a memory-mapped command FIFO one slot at a time.
+    #[inline]
+    pub fn iter_volatile_refs(&mut self) -> VolatileIntoIter<'_, T> {
+        // SAFETY: `self` stays mutably borrowed for as long as the iterator is alive.
+        unsafe { VolatileWriteOnlySlice::new_unchecked(self.data, self.len) }.into_iter()
+    }
+
+    /// Copies all elements from `src` into `self`, starting at `offset`,
+    /// performing one volatile write per element, in order,
+    /// followed by a single `compiler_fence(final_order)`.
+    ///
+    /// This allows for batching a sequence of volatile writes (e.g. ring descriptors)
+    /// behind a single fence, rather than a fence per element.
+    ///
+    /// Note that a compiler fence only restricts reordering by the compiler,
+    /// not by the hardware.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// if the length of `src` is greater than `self.len - offset`,
+    /// or if `final_order` is `Ordering::Relaxed`.
+    #[inline]
+    #[track_caller]
+    pub fn write_all_volatile_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+        final_order: Ordering,
+    ) where
+        T: Copy,
+    {
+        let src = src.as_ref();
+
+        assert!(
+            final_order != Ordering::Relaxed,
+            "there is no such thing as a relaxed compiler fence"
+        );
+
+        self.write_copying_from_slice_at(src, offset);
+
+        compiler_fence(final_order);
+    }
+
+    /// Copies all elements from `src` into `self`, starting at `offset`,
+    /// performing one volatile write per element, in order,
+    /// with a `compiler_fence(between)` between each two consecutive writes.
+    ///
+    /// This is stricter than [`Self::write_all_volatile_from_slice_at`],
+    /// e.g. for devices that latch on each individual write.
+    ///
+    /// Note that a compiler fence only restricts reordering by the compiler,
+    /// not by the hardware.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// if the length of `src` is greater than `self.len - offset`,
+    /// or if `between` is `Ordering::Relaxed`.
+    #[inline]
+    #[track_caller]
+    pub fn write_ordered_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+        between: Ordering,
+    ) where
+        T: Copy,
+    {
+        let src = src.as_ref();
+
+        super::assert_range_in_bounds(offset, src.len(), self.len);
+        assert!(
+            between != Ordering::Relaxed,
+            "there is no such thing as a relaxed compiler fence"
+        );
 
         for (index, item) in src.iter().enumerate() {
+            if index > 0 {
+                compiler_fence(between);
+            }
+
+            // SAFETY: `offset + index` was checked to be within bounds.
             unsafe {
-                self.data.add(offset + index).write_volatile(item.clone());
+                ptr::write_volatile(self.data.add(offset + index), *item);
             }
         }
     }
 
+    /// Divides one write-only slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding
+    /// the index `mid` itself) and the second will contain all
+    /// indices from `[mid, len)` (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
     #[inline]
-    fn write_copying_from_slice_at(&mut self, src: &[T], offset: usize)
-    where
-        T: Copy,
-    {
-        assert!(src.len() <= self.len - offset);
+    #[track_caller]
+    pub fn split_at(self, mid: usize) -> (Self, Self) {
+        super::assert_position_in_bounds(mid, self.len);
 
-        // SAFETY: `self` is valid for `self.len()` elements by definition,
-        // and `src` was checked to have a length less than `self.len - offset`.
-        // The slices cannot overlap because mutable references are exclusive.
+        // SAFETY: `[data, data + mid)` and `[data + mid, data + len)`
+        // are both in-bounds and do not overlap.
         unsafe {
-            // FIXME(regexident): Replace with efficient `core::ptr::volatile_copy_nonoverlapping`,
-            // if ever stabilized: https://github.com/rust-lang/rust/issues/58041
-
-            #[cfg(feature = "core_intrinsics")]
-            core::intrinsics::volatile_copy_nonoverlapping_memory(
-                self.data,
-                src.as_ptr(),
-                src.len(),
-            );
-
-            #[cfg(not(feature = "core_intrinsics"))]
-            {
-                let dst_ptr = self.data.add(offset);
-                for (index, item) in src.iter().enumerate() {
-                    ptr::write_volatile(dst_ptr.add(index), *item);
-                }
-            }
+            (
+                Self::new_unchecked(self.data, mid),
+                Self::new_unchecked(self.data.add(mid), self.len - mid),
+            )
         }
     }
-}
 
-impl<'a, T: 'a> From<&'a mut [T]> for VolatileWriteOnlySlice<'a, T> {
+    /// Performs a write of a memory location with the given value converted into `T`,
+    /// without reading or dropping the old value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
     #[inline]
-    fn from(slice: &'a mut [T]) -> Self {
-        unsafe { Self::from_raw_parts(slice.as_mut_ptr(), slice.len()) }
+    #[track_caller]
+    pub fn write_at_into<U>(&mut self, index: usize, value: U)
+    where
+        U: Into<T>,
+    {
+        self.write_at(index, value.into());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns a short-lived write-only slice with **dropping non-volatile** write access to the same memory,
+    /// borrowing rather than consuming `self`.
+    #[inline]
+    pub fn as_non_volatile(&mut self) -> WriteOnlySlice<'_, T> {
+        // SAFETY: `self` stays mutably borrowed for as long as the returned slice is alive.
+        unsafe { WriteOnlySlice::new_unchecked(self.data, self.len) }
+    }
 
-    use droptest::prelude::*;
+    /// Returns a write-only reference to the first `N` elements of the slice as an array,
+    /// and a write-only slice of the remaining elements.
+    ///
+    /// Returns `None` if the slice has fewer than `N` elements.
+    #[inline]
+    pub fn split_first_chunk<const N: usize>(
+        self,
+    ) -> Option<(VolatileWriteOnlyRef<'a, [T; N]>, Self)> {
+        if self.len < N {
+            return None;
+        }
 
-    #[test]
-    fn from_raw_parts() {
-        let registry = DropRegistry::default();
-        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+        // SAFETY: `[data, data + N)` and `[data + N, data + len)`
+        // are both in-bounds and do not overlap.
+        unsafe {
+            Some((
+                VolatileWriteOnlyRef::new_unchecked(self.data as *mut [T; N]),
+                Self::new_unchecked(self.data.add(N), self.len - N),
+            ))
+        }
+    }
 
-        let reference = unsafe { VolatileWriteOnlySlice::from_raw_parts(&mut guards, 3) };
+    /// Writes each item yielded by `iter` in order, starting at index `0`, without reading or dropping the old values,
+    /// expecting it to yield exactly `self.len()` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `iter` yields fewer or more than `self.len()` items.
+    #[inline]
+    #[track_caller]
+    pub fn write_all_from_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
 
-        std::mem::drop(reference);
+        for index in 0..self.len {
+            let Some(item) = iter.next() else {
+                panic!(
+                    "iterator yielded fewer items than the slice's length of {}",
+                    self.len
+                );
+            };
 
-        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+            unsafe {
+                self.write_at_unchecked(index, item);
+            }
+        }
 
-        std::mem::drop(guards);
+        assert!(
+            iter.next().is_none(),
+            "iterator yielded more items than the slice's length of {}",
+            self.len
+        );
+    }
 
-        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    /// Unflattens a write-only slice into a write-only slice of `N`-element arrays,
+    /// of length `self.len() / N`.
+    ///
+    /// Returns `None` if `N` is zero, or if the slice's length is not a multiple of `N`.
+    #[inline]
+    pub fn unflatten<const N: usize>(self) -> Option<VolatileWriteOnlySlice<'a, [T; N]>> {
+        if N == 0 || !self.len.is_multiple_of(N) {
+            return None;
+        }
+
+        // SAFETY: `N` consecutive `T`s have the same layout as `[T; N]`.
+        Some(unsafe {
+            VolatileWriteOnlySlice::new_unchecked(self.data as *mut [T; N], self.len / N)
+        })
     }
 
-    #[test]
-    fn from() {
-        let registry = DropRegistry::default();
-        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+    /// Copies all elements from `src` into the elements of `self` within `range`,
+    /// performing one volatile write per element.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `range` is out of bounds,
+    /// or if its length differs from the length of `src`.
+    #[inline]
+    #[track_caller]
+    pub fn set_slice(&mut self, range: Range<usize>, src: &[T])
+    where
+        T: Copy,
+    {
+        assert!(
+            range.start <= range.end,
+            "slice index starts at {} but ends at {}",
+            range.start,
+            range.end
+        );
+        super::assert_range_in_bounds(range.start, range.len(), self.len);
+        assert!(
+            range.len() == src.len(),
+            "destination and source slices have different lengths"
+        );
 
-        let reference = VolatileWriteOnlySlice::from(&mut guards[..]);
+        self.write_copying_from_slice_at(src, range.start);
+    }
 
-        std::mem::drop(reference);
+    /// Writes the value at `index` without reading or dropping the old value, returning an error instead of panicking if `index` is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `index` is out of bounds, dropping `value`.
+    #[inline]
+    pub fn try_write_at(&mut self, index: usize, value: T) -> Result<(), CapacityError> {
+        CapacityError::check(index.saturating_add(1), self.len)?;
 
-        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+        unsafe {
+            self.write_at_unchecked(index, value);
+        }
 
-        std::mem::drop(guards);
+        Ok(())
+    }
 
-        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    /// Clones the elements from `src` into `self`, starting at `offset`, performing one volatile write per element.
+    ///
+    /// Same as [`WriteFromSliceAt::write_cloning_from_slice_at`], but accepting any source
+    /// that can be viewed as a slice, such as an array or a `Vec<T>`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn write_cloning_from_slice_at(&mut self, src: &(impl AsRef<[T]> + ?Sized), offset: usize)
+    where
+        T: Clone,
+    {
+        WriteFromSliceAt::write_cloning_from_slice_at(self, src.as_ref(), offset);
     }
 
-    #[test]
-    fn write_at() {
-        let registry = DropRegistry::default();
-        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
-            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
-        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+    /// Copies all elements from `src` into `self`, starting at `offset`, without reading or dropping the old values, performing one volatile write per element.
+    ///
+    /// Same as [`WriteFromSliceAt::write_copying_from_slice_at`], but accepting any source
+    /// that can be viewed as a slice, such as an array or a `Vec<T>`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `offset` is greater than `self.len`,
+    /// or if the length of `src` is greater than `self.len - offset`.
+    #[inline]
+    #[track_caller]
+    pub fn write_copying_from_slice_at(&mut self, src: &(impl AsRef<[T]> + ?Sized), offset: usize)
+    where
+        T: Copy,
+    {
+        WriteFromSliceAt::write_copying_from_slice_at(self, src.as_ref(), offset);
+    }
 
-        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
-        slice.write_at(1, new_guard);
+    /// Clones the elements from `src` into `self`, starting at `offset`, without dropping the old values, returning an error instead of panicking if `src` does not fit into `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `offset + src.len()` is greater than `self.len()`.
+    #[inline]
+    pub fn try_write_cloning_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+    ) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        let src = src.as_ref();
 
-        assert_eq!(guards[1].id(), new_id);
-        assert_eq!(guards[1].value(), &3);
+        CapacityError::check(offset.saturating_add(src.len()), self.len)?;
 
-        assert_no_drop!(registry, old_ids[1]);
-        assert_drop_stats!(registry, { created: 4, dropped: 0 });
+        self.write_cloning_from_slice_at(src, offset);
+
+        Ok(())
     }
 
-    #[test]
-    #[should_panic]
-    fn write_at_out_of_bounds() {
-        let registry = DropRegistry::default();
-        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
-        let new_guard = registry.new_guard_for(3);
+    /// Copies all elements from `src` into `self`, starting at `offset`, returning an error instead of panicking if `src` does not fit into `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if `offset + src.len()` is greater than `self.len()`.
+    #[inline]
+    pub fn try_write_copying_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+    ) -> Result<(), CapacityError>
+    where
+        T: Copy,
+    {
+        let src = src.as_ref();
 
-        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
-        slice.write_at(10, new_guard);
+        CapacityError::check(offset.saturating_add(src.len()), self.len)?;
+
+        self.write_copying_from_slice_at(src, offset);
+
+        Ok(())
     }
 
-    #[test]
-    fn write_cloning_from_slice_at() {
-        let registry = DropRegistry::default();
-        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
-            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
-        let new_guards: Vec<_> = (5..8).map(|i| registry.new_guard_for(i)).collect();
+    /// Copies the elements from `src` into every `stride`-th element of `self`, starting at `offset`,
+    /// i.e. `src[k]` into `self[offset + k * stride]`, without reading or dropping the old values.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `stride` is zero, or if `offset + (src.len() - 1) * stride`
+    /// is out of bounds (for non-empty `src`).
+    #[inline]
+    #[track_caller]
+    pub fn write_strided_from_slice_at(
+        &mut self,
+        src: &(impl AsRef<[T]> + ?Sized),
+        offset: usize,
+        stride: usize,
+    ) where
+        T: Copy,
+    {
+        let src = src.as_ref();
 
-        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
-        slice.write_cloning_from_slice_at(&new_guards[..], 1);
+        super::assert_strided_in_bounds(self.len, src.len(), offset, stride);
 
-        assert_ne!(guards[1].id(), old_ids[1]);
-        assert_eq!(guards[1].value(), &5);
-        assert_ne!(guards[2].id(), old_ids[1]);
-        assert_eq!(guards[2].value(), &6);
-        assert_ne!(guards[3].id(), old_ids[2]);
-        assert_eq!(guards[3].value(), &7);
+        // SAFETY: the last (and thus every) index was checked to be in-bounds.
+        for (k, item) in src.iter().enumerate() {
+            unsafe {
+                self.write_at_unchecked(offset + k * stride, *item);
+            }
+        }
+    }
 
-        assert_no_drop!(registry, old_ids[1]);
-        assert_no_drop!(registry, old_ids[2]);
-        assert_no_drop!(registry, old_ids[3]);
-        assert_drop_stats!(registry, { created: 11, dropped: 0 });
+    /// Writes `T::default()` into each element of the slice, without reading or dropping the old values.
+    #[inline]
+    pub fn write_default(&mut self)
+    where
+        T: Default,
+    {
+        self.write_indexed_with(|_| T::default());
     }
 
-    #[test]
-    fn write_copying_from_slice_at() {
-        let mut values: Vec<_> = (0..5).collect();
-        let new_values: Vec<_> = (5..8).collect();
+    /// Converts the write-only slice back into a regular mutable slice,
+    /// re-introducing read-access.
+    ///
+    /// # Safety
+    ///
+    /// The slice's values must be initialized, and the caller must have exclusive
+    /// access to them for the duration of lifetime `'a`.
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    #[inline]
+    pub unsafe fn assume_readable(self) -> &'a mut [T] {
+        core::slice::from_raw_parts_mut(self.data, self.len)
+    }
 
-        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
-        slice.write_copying_from_slice_at(&new_values[..], 1);
+    /// Divides one write-only slice into two halves.
+    ///
+    /// For odd lengths the first half gets the extra element,
+    /// i.e. it splits at `len - len / 2`.
+    #[inline]
+    pub fn halve(self) -> (Self, Self) {
+        let mid = self.len - self.len / 2;
+
+        self.split_at(mid)
+    }
+}
+
+impl<'a, T: 'a, const N: usize> VolatileWriteOnlySlice<'a, [T; N]> {
+    /// Flattens a write-only slice of `N`-element arrays into a write-only slice of
+    /// their elements, of length `self.len() * N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the resulting slice would overflow a `usize`
+    /// (which is only possible for zero-sized `T`).
+    #[inline]
+    #[track_caller]
+    pub fn flatten(self) -> VolatileWriteOnlySlice<'a, T> {
+        let len = self.len.checked_mul(N).expect("slice len overflow");
+
+        // SAFETY: `[T; N]` has the same layout as `N` consecutive `T`s.
+        unsafe { VolatileWriteOnlySlice::new_unchecked(self.data as *mut T, len) }
+    }
+}
+
+impl<'a, T: 'a> VolatileWriteOnlySlice<'a, MaybeUninit<T>> {
+    /// Writes the initialized value `value` into the uninitialized slot at `index`.
+    ///
+    /// Use [`WriteAt::write_at`] for writing (possibly uninitialized) `MaybeUninit<T>` values instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn write_init_at(&mut self, index: usize, value: T) {
+        self.write_at(index, MaybeUninit::new(value));
+    }
+}
+
+impl<'a, T: 'a> WriteAt<T> for VolatileWriteOnlySlice<'a, T> {
+    #[cfg_attr(not(feature = "debug-no-inline"), inline)]
+    #[cfg_attr(feature = "debug-no-inline", inline(never))]
+    #[track_caller]
+    fn write_at(&mut self, index: usize, value: T) {
+        super::assert_index_in_bounds(index, self.len);
+
+        unsafe {
+            self.write_at_unchecked(index, value);
+        }
+    }
+
+    #[cfg_attr(not(feature = "debug-no-inline"), inline)]
+    #[cfg_attr(feature = "debug-no-inline", inline(never))]
+    #[track_caller]
+    unsafe fn write_at_unchecked(&mut self, index: usize, value: T) {
+        super::debug_assert_index_in_bounds::<T>(index, self.len);
+
+        self.data.add(index).write_volatile(value);
+    }
+}
+
+impl<'a, T: 'a> WriteFromSliceAt<T> for VolatileWriteOnlySlice<'a, T> {
+    #[cfg_attr(not(feature = "debug-no-inline"), inline)]
+    #[cfg_attr(feature = "debug-no-inline", inline(never))]
+    #[track_caller]
+    fn write_cloning_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Clone,
+    {
+        super::assert_range_in_bounds(offset, src.len(), self.len);
+
+        // An empty `src` is a no-op. Bailing out before any pointer arithmetic
+        // also avoids forming `data + offset` for `offset == len`.
+        if src.is_empty() {
+            return;
+        }
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len() - offset`.
+        // The slices cannot overlap because mutable references are exclusive.
+
+        for (index, item) in src.iter().enumerate() {
+            unsafe {
+                self.data.add(offset + index).write_volatile(item.clone());
+            }
+        }
+    }
+
+    #[cfg_attr(not(feature = "debug-no-inline"), inline)]
+    #[cfg_attr(feature = "debug-no-inline", inline(never))]
+    #[track_caller]
+    fn write_copying_from_slice_at(&mut self, src: &[T], offset: usize)
+    where
+        T: Copy,
+    {
+        super::assert_range_in_bounds(offset, src.len(), self.len);
+
+        // An empty `src` is a no-op. Bailing out before any pointer arithmetic
+        // also avoids forming `data + offset` for `offset == len`.
+        if src.is_empty() {
+            return;
+        }
+
+        // SAFETY: `self` is valid for `self.len()` elements by definition,
+        // and `src` was checked to have a length less than `self.len - offset`.
+        // The slices cannot overlap because mutable references are exclusive.
+        //
+        // Deliberately not a (volatile) memcpy: that would leave the width and
+        // number of the individual stores unspecified, while memory-mapped
+        // registers usually expect exactly one store of `T` per element.
+        unsafe {
+            let dst_ptr = self.data.add(offset);
+            for (index, item) in src.iter().enumerate() {
+                ptr::write_volatile(dst_ptr.add(index), *item);
+            }
+        }
+    }
+}
+
+impl<'a, T: 'a> From<&'a mut [T]> for VolatileWriteOnlySlice<'a, T> {
+    #[inline]
+    fn from(slice: &'a mut [T]) -> Self {
+        unsafe { Self::new_unchecked(slice.as_mut_ptr(), slice.len()) }
+    }
+}
+
+impl<'a, T: 'a, const N: usize> From<&'a mut [T; N]> for VolatileWriteOnlySlice<'a, T> {
+    #[inline]
+    fn from(array: &'a mut [T; N]) -> Self {
+        unsafe { Self::new_unchecked(array.as_mut_ptr(), N) }
+    }
+}
+
+impl<'a, T: 'a> IntoIterator for VolatileWriteOnlySlice<'a, T> {
+    type Item = VolatileWriteOnlyRef<'a, T>;
+    type IntoIter = VolatileIntoIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        VolatileIntoIter {
+            data: self.data,
+            start: 0,
+            end: self.len,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A consuming iterator over the elements of a [`VolatileWriteOnlySlice`],
+/// yielding a write-only reference per element.
+pub struct VolatileIntoIter<'a, T: 'a> {
+    data: *mut T,
+    start: usize,
+    end: usize,
+    _phantom: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> Iterator for VolatileIntoIter<'a, T> {
+    type Item = VolatileWriteOnlyRef<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        let index = self.start;
+        self.start += 1;
+
+        // SAFETY: `index` is in-bounds and gets yielded only once.
+        Some(unsafe { VolatileWriteOnlyRef::new_unchecked(self.data.add(index)) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for VolatileIntoIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: `self.end` is in-bounds and gets yielded only once.
+        Some(unsafe { VolatileWriteOnlyRef::new_unchecked(self.data.add(self.end)) })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for VolatileIntoIter<'a, T> {}
+
+impl<'a, T: 'a> FusedIterator for VolatileIntoIter<'a, T> {}
+
+// SAFETY: a `VolatileWriteOnlySlice` has exclusive access to its `len` elements for `'a`,
+// and its splitting methods (e.g. `split_at`) only ever hand out disjoint ranges of them.
+// Since volatile writes are not atomic, it is this disjointness that makes handing slices to
+// other threads (e.g. for concurrently driving separate MMIO registers) free of races.
+// Values written from the receiving thread end up owned by the sender's memory, hence `T: Send`.
+unsafe impl<'a, T: 'a> Send for VolatileWriteOnlySlice<'a, T> where T: Send {}
+
+// SAFETY: all volatile writes take `&mut self`. The `&self` methods only read the
+// pointer and length, so a shared `&VolatileWriteOnlySlice` can never issue a write.
+unsafe impl<'a, T: 'a> Sync for VolatileWriteOnlySlice<'a, T> where T: Sync {}
+
+/// Compares by identity (i.e. same base pointer and length), not by value.
+impl<'a, T: 'a> PartialEq for VolatileWriteOnlySlice<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.data, other.data) && self.len == other.len
+    }
+}
+
+impl<'a, T: 'a> Eq for VolatileWriteOnlySlice<'a, T> {}
+
+/// Hashes by identity (i.e. by base pointer and length), not by value.
+impl<'a, T: 'a> Hash for VolatileWriteOnlySlice<'a, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.len.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{PutAt, Write};
+
+    use droptest::prelude::*;
+
+    #[test]
+    #[cfg(feature = "raw")]
+    #[allow(clippy::drop_non_drop)]
+    fn from_raw_parts_mut() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let reference =
+            unsafe { VolatileWriteOnlySlice::from_raw_parts_mut(guards.as_mut_ptr(), 3) };
+
+        std::mem::drop(reference);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+
+        std::mem::drop(guards);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    }
+
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    fn from() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let reference = VolatileWriteOnlySlice::from(&mut guards[..]);
+
+        std::mem::drop(reference);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 0 });
+
+        std::mem::drop(guards);
+
+        assert_drop_stats!(registry, { created: 3, dropped: 3 });
+    }
+
+    #[test]
+    fn from_array() {
+        let mut values = [0, 1, 2];
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values);
+
+        assert_eq!(slice.len(), 3);
+
+        slice.write_at(1, 42);
+
+        assert_eq!(values, [0, 42, 2]);
+    }
+
+    #[test]
+    fn write_at() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
+        slice.write_at(1, new_guard);
+
+        assert_eq!(guards[1].id(), new_id);
+        assert_eq!(guards[1].value(), &3);
+
+        assert_no_drop!(registry, old_ids[1]);
+        assert_drop_stats!(registry, { created: 4, dropped: 0 });
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_at_out_of_bounds() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+        let new_guard = registry.new_guard_for(3);
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
+        slice.write_at(10, new_guard);
+    }
+
+    #[test]
+    fn write_cloning_from_slice_at() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..5).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let new_guards: Vec<_> = (5..8).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
+        slice.write_cloning_from_slice_at(&new_guards[..], 1);
+
+        assert_ne!(guards[1].id(), old_ids[1]);
+        assert_eq!(guards[1].value(), &5);
+        assert_ne!(guards[2].id(), old_ids[1]);
+        assert_eq!(guards[2].value(), &6);
+        assert_ne!(guards[3].id(), old_ids[2]);
+        assert_eq!(guards[3].value(), &7);
+
+        assert_no_drop!(registry, old_ids[1]);
+        assert_no_drop!(registry, old_ids[2]);
+        assert_no_drop!(registry, old_ids[3]);
+        assert_drop_stats!(registry, { created: 11, dropped: 0 });
+    }
+
+    #[test]
+    fn write_copying_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+        let new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_copying_from_slice_at(&new_values[..], 1);
+
+        assert_eq!(values, &[0, 5, 6, 7, 4]);
+    }
+
+    #[test]
+    fn from_slice_at_empty_at_end() {
+        let mut values = [0_u32; 3];
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values);
+        slice.write_cloning_from_slice_at(&[], 3);
+        slice.write_copying_from_slice_at(&[], 3);
+
+        assert_eq!(values, [0, 0, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 4 out of bounds for write-only slice of length 3")]
+    fn from_slice_at_empty_out_of_bounds() {
+        let mut values = [0_u32; 3];
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values);
+        slice.write_cloning_from_slice_at(&[], 4);
+    }
+
+    #[test]
+    fn write_suffix_from_slice() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let pos = slice.write_suffix_from_slice(0, &[5, 6]);
+        let pos = slice.write_suffix_from_slice(pos, &[7]);
+
+        assert_eq!(pos, 3);
+        assert_eq!(values, &[5, 6, 7, 3, 4]);
+    }
+
+    #[test]
+    fn get_many() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let [mut first, mut second] = slice.get_many([3, 1]).unwrap();
+        first.write(30);
+        second.write(10);
+
+        assert_eq!(values, &[0, 10, 2, 30, 4]);
+    }
+
+    #[test]
+    fn get_many_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.get_many([1, 5]).is_none());
+    }
+
+    #[test]
+    fn get_many_overlapping() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.get_many([1, 2, 1]).is_none());
+    }
+
+    #[test]
+    fn write_indexed_with() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<_> = (0..3).map(|i| registry.new_guard_for(i)).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
+        slice.write_indexed_with(|index| registry.new_guard_for(index * 10));
+
+        let values: Vec<_> = guards.iter().map(|guard| *guard.value()).collect();
+        assert_eq!(values, &[0, 10, 20]);
+
+        assert_drop_stats!(registry, { created: 6, dropped: 0 });
+    }
+
+    #[test]
+    fn chunks_ref() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        let lens: Vec<_> = slice
+            .chunks_ref(2)
+            .map(|mut chunk| {
+                chunk.write_at(0, 42);
+                chunk.len()
+            })
+            .collect();
+
+        assert_eq!(lens, &[2, 2, 1]);
+
+        slice.write_at(1, 43);
+
+        assert_eq!(values, &[42, 43, 42, 3, 42]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_ref_zero() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let _ = slice.chunks_ref(0);
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::HashSet;
+
+        let mut values = [0, 1, 2];
+        let ptr = values.as_mut_ptr();
+
+        let (whole, prefix, suffix, whole_again) = unsafe {
+            (
+                VolatileWriteOnlySlice::new_unchecked(ptr, 3),
+                VolatileWriteOnlySlice::new_unchecked(ptr, 2),
+                VolatileWriteOnlySlice::new_unchecked(ptr.add(1), 2),
+                VolatileWriteOnlySlice::new_unchecked(ptr, 3),
+            )
+        };
+
+        assert!(whole == whole_again);
+        assert!(whole != prefix);
+        assert!(prefix != suffix);
+
+        let set: HashSet<_> = [whole, prefix, suffix, whole_again].into_iter().collect();
+
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn write_init_at() {
+        let registry = DropRegistry::default();
+        let mut guards: Vec<MaybeUninit<DropGuard<i32>>> =
+            (0..3).map(|_| MaybeUninit::uninit()).collect();
+        let (new_id, new_guard) = registry.new_guard_for(42).by_id();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
+        slice.write_init_at(1, new_guard);
+
+        let guard = unsafe { guards[1].assume_init_read() };
+
+        assert_eq!(guard.id(), new_id);
+        assert_eq!(guard.value(), &42);
+
+        assert_drop_stats!(registry, { created: 1, dropped: 0 });
+    }
+
+    #[test]
+    fn byte_len() {
+        let mut values: Vec<u32> = vec![0; 5];
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.element_size(), 4);
+        assert_eq!(slice.byte_len(), 20);
+    }
+
+    #[test]
+    #[cfg(feature = "raw")]
+    fn from_non_null() {
+        let mut values = [0, 1, 2];
+
+        let mut slice = unsafe {
+            VolatileWriteOnlySlice::from_non_null(ptr::NonNull::from(&mut values).cast(), 3)
+        };
+        slice.write_at(1, 42);
+
+        assert_eq!(values, [0, 42, 2]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut values: Vec<_> = (0..4).collect();
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let mut iter = slice.into_iter();
+
+        assert_eq!(iter.len(), 4);
+
+        iter.next().unwrap().write(10);
+        iter.next_back().unwrap().write(13);
+
+        assert_eq!(iter.len(), 2);
+
+        for (index, mut reference) in iter.enumerate() {
+            reference.write(index + 20);
+        }
+
+        assert_eq!(values, &[10, 20, 21, 13]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    fn write_at_unchecked_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        unsafe { slice.write_at_unchecked(5, 42) };
+    }
+
+    #[test]
+    fn iter_volatile_refs() {
+        let mut values: Vec<_> = (0..4).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        for (index, mut reference) in slice.iter_volatile_refs().enumerate() {
+            reference.write(index + 10);
+        }
+
+        slice.write_at(0, 20);
+
+        assert_eq!(values, &[20, 11, 12, 13]);
+    }
+
+    #[test]
+    fn write_all_volatile_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+        let new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_all_volatile_from_slice_at(&new_values[..], 1, Ordering::Release);
+
+        assert_eq!(values, &[0, 5, 6, 7, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "there is no such thing as a relaxed compiler fence")]
+    fn write_all_volatile_from_slice_at_relaxed() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+            slice.write_all_volatile_from_slice_at(&[5, 6], 0, Ordering::Relaxed);
+        }));
+
+        // The panic must happen before any of the writes.
+        assert_eq!(values, &[0, 1, 2, 3, 4]);
+
+        if let Err(payload) = result {
+            std::panic::resume_unwind(payload);
+        }
+    }
+
+    #[test]
+    fn write_ordered_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+        let new_values: Vec<_> = (5..8).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_ordered_from_slice_at(&new_values[..], 1, Ordering::SeqCst);
+        slice.write_ordered_from_slice_at(&[], 5, Ordering::Release);
 
         assert_eq!(values, &[0, 5, 6, 7, 4]);
     }
+
+    #[test]
+    #[should_panic(expected = "there is no such thing as a relaxed compiler fence")]
+    fn write_ordered_from_slice_at_relaxed() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_ordered_from_slice_at(&[5], 0, Ordering::Relaxed);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    fn write_ordered_from_slice_at_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_ordered_from_slice_at(&[5, 6], 4, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn split_at() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let (mut left, mut right) = slice.split_at(2);
+
+        assert_eq!((left.len(), right.len()), (2, 3));
+
+        left.write_at(1, 42);
+        right.write_at(0, 43);
+
+        assert_eq!(values, &[0, 42, 43, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let _ = slice.split_at(6);
+    }
+
+    #[test]
+    fn send() {
+        let mut values: Vec<_> = (0..4).collect();
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let (mut left, mut right) = slice.split_at(2);
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || left.write_at(0, 10));
+            scope.spawn(move || right.write_at(1, 13));
+        });
+
+        assert_eq!(values, &[10, 1, 2, 13]);
+    }
+
+    #[test]
+    fn write_at_into() {
+        let mut values: Vec<u64> = vec![0; 3];
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_at_into(2, 3u32);
+
+        assert_eq!(values, &[0, 0, 3]);
+    }
+
+    #[test]
+    fn as_non_volatile() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.as_non_volatile().put_at(0, 10);
+        slice.write_at(2, 12);
+
+        assert_eq!(values, &[10, 1, 12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 100 out of bounds for write-only slice of length 5")]
+    fn write_cloning_from_slice_at_offset_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_cloning_from_slice_at(&[], 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for write-only slice of length 5")]
+    fn write_cloning_from_slice_at_offset_overflow() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_cloning_from_slice_at(&[1, 2], usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 100 out of bounds for write-only slice of length 5")]
+    fn write_copying_from_slice_at_offset_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_copying_from_slice_at(&[], 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for write-only slice of length 5")]
+    fn write_copying_from_slice_at_offset_overflow() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_copying_from_slice_at(&[1, 2], usize::MAX);
+    }
+
+    #[test]
+    fn split_first_chunk() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let (mut head, mut tail) = slice.split_first_chunk::<2>().unwrap();
+        head.write([10, 11]);
+        tail.write_at(0, 12);
+
+        assert_eq!(tail.len(), 3);
+        assert_eq!(values, &[10, 11, 12, 3, 4]);
+    }
+
+    #[test]
+    fn split_first_chunk_too_short() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.split_first_chunk::<6>().is_none());
+    }
+
+    #[test]
+    fn write_all_from_iter() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_all_from_iter(10..13);
+
+        assert_eq!(values, &[10, 11, 12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer")]
+    fn write_all_from_iter_too_few() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_all_from_iter(10..12);
+    }
+
+    #[test]
+    #[should_panic(expected = "more")]
+    fn write_all_from_iter_too_many() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_all_from_iter(10..14);
+    }
+
+    #[test]
+    fn flatten() {
+        let mut pixels: Vec<[u8; 4]> = vec![[0; 4]; 2];
+
+        let slice = VolatileWriteOnlySlice::from(&mut pixels[..]);
+        let mut flat = slice.flatten();
+
+        assert_eq!(flat.len(), 8);
+
+        flat.write_at(5, 42);
+
+        assert_eq!(pixels, &[[0, 0, 0, 0], [0, 42, 0, 0]]);
+    }
+
+    #[test]
+    fn unflatten() {
+        let mut values: Vec<u8> = vec![0; 8];
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        let mut pixels = slice.unflatten::<4>().unwrap();
+
+        assert_eq!(pixels.len(), 2);
+
+        pixels.write_at(1, [1, 2, 3, 4]);
+
+        assert_eq!(values, &[0, 0, 0, 0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn unflatten_uneven() {
+        let mut values: Vec<u8> = vec![0; 7];
+
+        let slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert!(slice.unflatten::<4>().is_none());
+    }
+
+    #[test]
+    fn set_slice() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.set_slice(1..3, &[10, 11]);
+
+        assert_eq!(values, &[0, 10, 11, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn set_slice_length_mismatch() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.set_slice(1..3, &[10]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of bounds for write-only slice of length 5")]
+    fn set_slice_out_of_bounds() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.set_slice(4..6, &[10, 11]);
+    }
+
+    #[test]
+    fn try_write_at() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.try_write_at(2, 42), Ok(()));
+        assert_eq!(
+            slice.try_write_at(3, 43),
+            Err(CapacityError {
+                needed: 4,
+                available: 3
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 42]);
+    }
+
+    #[test]
+    fn try_write_cloning_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.try_write_cloning_from_slice_at(&[5, 6], 3), Ok(()));
+        assert_eq!(
+            slice.try_write_cloning_from_slice_at(&[7, 8], 4),
+            Err(CapacityError {
+                needed: 6,
+                available: 5
+            })
+        );
+        assert_eq!(
+            slice.try_write_cloning_from_slice_at(&[], usize::MAX),
+            Err(CapacityError {
+                needed: usize::MAX,
+                available: 5
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn try_write_copying_from_slice_at() {
+        let mut values: Vec<_> = (0..5).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+
+        assert_eq!(slice.try_write_copying_from_slice_at(&[5, 6], 3), Ok(()));
+        assert_eq!(
+            slice.try_write_copying_from_slice_at(&[7, 8], 4),
+            Err(CapacityError {
+                needed: 6,
+                available: 5
+            })
+        );
+        assert_eq!(
+            slice.try_write_copying_from_slice_at(&[], usize::MAX),
+            Err(CapacityError {
+                needed: usize::MAX,
+                available: 5
+            })
+        );
+
+        assert_eq!(values, &[0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn write_strided_from_slice_at() {
+        let mut values: Vec<_> = (0..7).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_strided_from_slice_at(&[10, 11, 12], 1, 2);
+
+        assert_eq!(values, &[0, 10, 2, 11, 4, 12, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 7 out of bounds for write-only slice of length 7")]
+    fn write_strided_from_slice_at_out_of_bounds() {
+        let mut values: Vec<_> = (0..7).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_strided_from_slice_at(&[10, 11, 12], 1, 3);
+    }
+
+    #[test]
+    fn write_default() {
+        let mut values: Vec<u32> = vec![1, 2];
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_default();
+
+        assert_eq!(values, &[0, 0]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "raw", not(feature = "safe")))]
+    fn assume_readable() {
+        let mut values: Vec<_> = (0..3).collect();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut values[..]);
+        slice.write_at(1, 42);
+
+        let readable = unsafe { slice.assume_readable() };
+
+        assert_eq!(readable, &[0, 42, 2]);
+    }
+
+    #[test]
+    fn halve() {
+        let mut even: Vec<_> = (0..4).collect();
+        let mut odd: Vec<_> = (0..5).collect();
+
+        let (left, right) = VolatileWriteOnlySlice::from(&mut even[..]).halve();
+
+        assert_eq!((left.len(), right.len()), (2, 2));
+
+        let (left, right) = VolatileWriteOnlySlice::from(&mut odd[..]).halve();
+
+        assert_eq!((left.len(), right.len()), (3, 2));
+    }
+
+    #[test]
+    #[allow(clippy::drop_non_drop)]
+    fn drop_does_not_drop_elements() {
+        let registry = DropRegistry::default();
+        let (old_ids, mut guards): (Vec<_>, Vec<_>) =
+            (0..3).map(|i| registry.new_guard_for(i).by_id()).unzip();
+        let (new_id, new_guard) = registry.new_guard_for(3).by_id();
+
+        let mut slice = VolatileWriteOnlySlice::from(&mut guards[..]);
+        slice.write_at(1, new_guard);
+
+        std::mem::drop(slice);
+
+        assert_no_drop!(registry, old_ids[1]);
+        assert_no_drop!(registry, new_id);
+        assert_drop_stats!(registry, { created: 4, dropped: 0 });
+
+        std::mem::drop(guards);
+
+        assert_drop_stats!(registry, { created: 4, dropped: 3 });
+    }
 }
@@ -0,0 +1,136 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Model-checked concurrency tests for the volatile types.
+//!
+//! Loom only tracks memory accesses made through its own primitives, so every
+//! element of the buffers below is paired with a [`loom::cell::UnsafeCell`],
+//! and every write is performed from within that cell's `with_mut` closure.
+//! Loom thus reports any two threads writing to the same element without
+//! synchronization as a data race, in any of the interleavings it explores.
+//!
+//! Run via `RUSTFLAGS="--cfg loom" cargo test --release --test loom`.
+
+#![cfg(loom)]
+
+use loom::{cell::UnsafeCell, sync::Arc, thread};
+
+use write_only::{prelude::*, Write};
+
+/// A `'static` buffer (as `loom::thread::spawn` requires),
+/// with one loom-tracked cell per element.
+struct TrackedBuffer {
+    values: *mut [u32],
+    cells: Box<[UnsafeCell<()>]>,
+}
+
+// SAFETY: the buffer only hands out its values once, via `take`.
+unsafe impl Send for TrackedBuffer {}
+unsafe impl Sync for TrackedBuffer {}
+
+impl TrackedBuffer {
+    fn new(len: usize) -> Arc<Self> {
+        Arc::new(Self {
+            values: Box::into_raw(vec![0; len].into_boxed_slice()),
+            cells: (0..len).map(|_| UnsafeCell::new(())).collect(),
+        })
+    }
+
+    /// Returns the buffer's values, to be split and handed out to threads.
+    ///
+    /// # Safety
+    ///
+    /// Must be called at most once.
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn take(&self) -> &'static mut [u32] {
+        unsafe { &mut *self.values }
+    }
+
+    /// Performs `write` as a tracked write to the element at `index`.
+    fn write(&self, index: usize, write: impl FnOnce()) {
+        self.cells[index].with_mut(|_| write());
+    }
+
+    /// Returns the buffer's final values, once all threads have been joined.
+    fn into_values(this: Arc<Self>) -> Vec<u32> {
+        let buffer = Arc::try_unwrap(this).ok().expect("buffer still shared");
+        unsafe { Box::from_raw(buffer.values) }.into_vec()
+    }
+}
+
+#[test]
+fn slice_split_at_disjoint_halves() {
+    loom::model(|| {
+        let buffer = TrackedBuffer::new(4);
+
+        let slice = VolatileWriteOnlySlice::from(unsafe { buffer.take() });
+        let (mut left, mut right) = slice.split_at(2);
+
+        let left_thread = thread::spawn({
+            let buffer = buffer.clone();
+            move || {
+                buffer.write(0, || left.write_at(0, 1));
+                buffer.write(1, || left.write_at(1, 2));
+            }
+        });
+        let right_thread = thread::spawn({
+            let buffer = buffer.clone();
+            move || {
+                buffer.write(2, || right.write_at(0, 3));
+                buffer.write(3, || right.write_at(1, 4));
+            }
+        });
+
+        left_thread.join().unwrap();
+        right_thread.join().unwrap();
+
+        assert_eq!(TrackedBuffer::into_values(buffer), &[1, 2, 3, 4]);
+    });
+}
+
+#[test]
+fn refs_disjoint_elements() {
+    loom::model(|| {
+        let buffer = TrackedBuffer::new(2);
+
+        let (first, second) = unsafe { buffer.take() }.split_at_mut(1);
+        let mut first = VolatileWriteOnlyRef::from(&mut first[0]);
+        let mut second = VolatileWriteOnlyRef::from(&mut second[0]);
+
+        let first_thread = thread::spawn({
+            let buffer = buffer.clone();
+            move || buffer.write(0, || first.write(1))
+        });
+        let second_thread = thread::spawn({
+            let buffer = buffer.clone();
+            move || buffer.write(1, || second.write(2))
+        });
+
+        first_thread.join().unwrap();
+        second_thread.join().unwrap();
+
+        assert_eq!(TrackedBuffer::into_values(buffer), &[1, 2]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Causality violation")]
+fn unsynchronized_writes_are_reported() {
+    loom::model(|| {
+        let buffer = TrackedBuffer::new(1);
+
+        let threads: Vec<_> = (0..2)
+            .map(|_| {
+                let buffer = buffer.clone();
+                // Only the tracked access is performed, as actually writing
+                // to the element from both threads would be undefined behavior.
+                thread::spawn(move || buffer.write(0, || {}))
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    });
+}
@@ -0,0 +1,258 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Tests exercising the crate's unsafe write paths, meant for being run under Miri
+//! in order to catch out-of-bounds accesses, as well as provenance and aliasing bugs.
+//!
+//! Run via `cargo +nightly miri test --test miri`.
+//!
+//! The tests run under a regular `cargo test` just as well.
+
+use write_only::{prelude::*, PutAt, Write, WriteAt};
+
+#[test]
+fn put_and_write_at() {
+    let mut values: Vec<String> = (0..4).map(|i| i.to_string()).collect();
+
+    let mut slice = WriteOnlySlice::from(&mut values[..]);
+    slice.put_at(0, "a".to_owned());
+    slice.put_at(3, "d".to_owned());
+
+    assert_eq!(values, ["a", "1", "2", "d"]);
+
+    let mut values = [0_u32; 4];
+
+    let mut slice = WriteOnlySlice::from(&mut values);
+    slice.write_at(1, 1);
+
+    let mut slice = VolatileWriteOnlySlice::from(&mut values);
+    slice.write_at(3, 3);
+
+    assert_eq!(values, [0, 1, 0, 3]);
+}
+
+#[test]
+fn from_slice_at() {
+    let mut values: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+    let new_values: Vec<String> = (5..8).map(|i| i.to_string()).collect();
+
+    let mut slice = WriteOnlySlice::from(&mut values[..]);
+    slice.put_cloning_from_slice_at(&new_values[..], 1);
+
+    assert_eq!(values, ["0", "5", "6", "7", "4"]);
+
+    let mut values: Vec<u32> = (0..5).collect();
+
+    let mut slice = WriteOnlySlice::from(&mut values[..]);
+    slice.put_copying_from_slice_at(&[5, 6], 0);
+    slice.write_copying_from_slice_at(&[7, 8], 3);
+
+    assert_eq!(values, [5, 6, 2, 7, 8]);
+
+    let mut values = [0_u32; 5];
+
+    let mut slice = VolatileWriteOnlySlice::from(&mut values);
+    slice.write_copying_from_slice_at(&[1, 2, 3], 2);
+
+    assert_eq!(values, [0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn split_at() {
+    let mut values = [0_u32; 5];
+
+    let slice = WriteOnlySlice::from(&mut values);
+    let (mut left, mut right) = slice.split_at(2);
+    left.write_at(1, 1);
+    right.write_at(0, 2);
+    right.write_at(2, 4);
+
+    assert_eq!(values, [0, 1, 2, 0, 4]);
+
+    let mut values = [0_u32; 5];
+
+    let slice = VolatileWriteOnlySlice::from(&mut values);
+    let (mut left, mut right) = slice.split_at(5);
+    left.write_at(4, 4);
+    assert!(right.is_empty());
+    right.write_copying_from_slice_at(&[], 0);
+
+    assert_eq!(values, [0, 0, 0, 0, 4]);
+}
+
+// The halves of a split derive their pointers from the parent's via pointer
+// arithmetic, so they keep provenance over their whole sub-range, and writing
+// through one of them must not invalidate the other.
+#[test]
+fn split_at_interleaved() {
+    let mut values = [0_u32; 6];
+
+    let slice = WriteOnlySlice::from(&mut values);
+    let (mut left, mut right) = slice.split_at(3);
+    right.write_at(0, 4);
+    left.write_at(2, 3);
+    right.write_at(2, 6);
+    left.write_at(0, 1);
+    right.write_at(1, 5);
+    left.write_at(1, 2);
+
+    assert_eq!(values, [1, 2, 3, 4, 5, 6]);
+
+    let mut values = [0_u32; 6];
+
+    let slice = VolatileWriteOnlySlice::from(&mut values);
+    let (mut left, mut right) = slice.split_at(3);
+    right.write_copying_from_slice_at(&[4, 5, 6], 0);
+    left.write_copying_from_slice_at(&[1, 2, 3], 0);
+    right.write_at(0, 7);
+    left.write_at(2, 8);
+
+    assert_eq!(values, [1, 2, 8, 7, 5, 6]);
+}
+
+#[test]
+fn split_at_ref_then_parent() {
+    let mut values: Vec<String> = (0..4).map(|i| i.to_string()).collect();
+
+    let mut slice = WriteOnlySlice::from(&mut values[..]);
+    {
+        let (mut left, mut right) = slice.split_at_ref(2);
+        right.put_at(1, "d".to_owned());
+        left.put_at(0, "a".to_owned());
+        right.put_at(0, "c".to_owned());
+    }
+    slice.put_at(1, "b".to_owned());
+
+    assert_eq!(values, ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn chunks_out_of_order() {
+    let mut values = [0_u32; 7];
+
+    let slice = WriteOnlySlice::from(&mut values);
+    let mut chunks: Vec<_> = slice.rchunks(3).collect();
+    for (index, chunk) in chunks.iter_mut().enumerate() {
+        chunk.write_at(0, index as u32 + 1);
+    }
+    chunks[0].write_at(2, 9);
+
+    assert_eq!(values, [3, 2, 0, 0, 1, 0, 9]);
+
+    let mut values = [0_u32; 7];
+
+    let mut slice = WriteOnlySlice::from(&mut values);
+    let mut head = slice.take(2);
+    let (mut arrays, mut remainder) = slice.as_chunks::<2>();
+    remainder.write_at(0, 7);
+    arrays.write_at(1, [5, 6]);
+    head.write_copying_from_slice_at(&[1, 2], 0);
+    arrays.write_at(0, [3, 4]);
+
+    assert_eq!(values, [1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn halve() {
+    let mut values = [0_u32; 5];
+
+    let slice = WriteOnlySlice::from(&mut values);
+    let (mut left, mut right) = slice.halve();
+    left.write_copying_from_slice_at(&[1, 2, 3], 0);
+    right.write_copying_from_slice_at(&[4, 5], 0);
+
+    assert_eq!(values, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn chunks_ref() {
+    let mut values = [0_u32; 7];
+
+    let mut slice = WriteOnlySlice::from(&mut values);
+    for (index, mut chunk) in slice.chunks_ref(3).enumerate() {
+        let len = chunk.len();
+        chunk.write_at(len - 1, index as u32 + 1);
+    }
+
+    assert_eq!(values, [0, 0, 1, 0, 0, 2, 3]);
+
+    let mut values = [0_u32; 7];
+
+    let mut slice = VolatileWriteOnlySlice::from(&mut values);
+    for (index, mut chunk) in slice.chunks_ref(3).enumerate() {
+        chunk.write_at(0, index as u32 + 1);
+    }
+
+    assert_eq!(values, [1, 0, 0, 2, 0, 0, 3]);
+}
+
+#[test]
+fn into_iter() {
+    let mut values = [0_u32; 4];
+
+    let slice = WriteOnlySlice::from(&mut values);
+    let mut iter = slice.into_iter();
+    iter.next_back().unwrap().put(4);
+    for (index, mut reference) in iter.enumerate() {
+        reference.write(index as u32 + 1);
+    }
+
+    assert_eq!(values, [1, 2, 3, 4]);
+
+    let mut values = [0_u32; 4];
+
+    let mut slice = VolatileWriteOnlySlice::from(&mut values);
+    for (index, mut reference) in slice.iter_volatile_refs().rev().enumerate() {
+        reference.write(index as u32 + 1);
+    }
+
+    assert_eq!(values, [4, 3, 2, 1]);
+}
+
+#[test]
+#[cfg(feature = "raw")]
+fn from_raw_parts_mut() {
+    let mut values: Vec<u32> = vec![0; 4];
+
+    // The pointer is derived from the whole buffer, not from a reference to
+    // its first element, so that it has provenance over all of its elements.
+    let data = values.as_mut_ptr();
+    let mut slice = unsafe { WriteOnlySlice::from_raw_parts_mut(data, values.len()) };
+    slice.write_at(3, 3);
+
+    let (mut left, _) = slice.split_at(2);
+    let mut left = left.as_volatile();
+    left.write_at(1, 1);
+
+    assert_eq!(values, [0, 1, 0, 3]);
+
+    let mut value = 0_u32;
+
+    let mut reference = unsafe { WriteOnlyRef::from_ptr(&mut value) };
+    reference.put(42);
+
+    assert_eq!(value, 42);
+}
+
+#[test]
+#[cfg(any(feature = "raw", not(feature = "safe")))]
+fn project_packed() {
+    #[repr(C, packed)]
+    struct Header {
+        tag: u8,
+        name: String,
+    }
+
+    let mut header = Header {
+        tag: 1,
+        name: "old".to_owned(),
+    };
+
+    let reference = WriteOnlyRef::from(&mut header);
+    let mut name = unsafe { reference.project_packed(|ptr| std::ptr::addr_of_mut!((*ptr).name)) };
+    name.put("new".to_owned());
+
+    let Header { tag, name } = header;
+    assert_eq!((tag, name.as_str()), (1, "new"));
+}
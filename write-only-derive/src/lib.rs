@@ -0,0 +1,173 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Derive macros for the `write-only` crate.
+//!
+//! Use via the `write-only` crate's `derive` feature, rather than directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Derives per-field write-only projections for a struct with named fields.
+///
+/// For a struct `Foo` this generates a trait `FooWriteOnlyFields`
+/// (with the same visibility as `Foo`), providing a `set_<field>` method per field,
+/// implemented for both `WriteOnlyRef<'_, Foo>` (dropping the field's old value)
+/// and `VolatileWriteOnlyRef<'_, Foo>` (performing a volatile write).
+///
+/// ```
+/// use write_only::{prelude::*, WriteOnlyFields};
+///
+/// #[derive(WriteOnlyFields)]
+/// #[repr(C)]
+/// struct Registers {
+///     control: u32,
+///     status: u16,
+/// }
+///
+/// let mut registers = Registers { control: 0, status: 0 };
+///
+/// let mut write_only = VolatileWriteOnlyRef::from(&mut registers);
+/// write_only.set_control(0b101);
+/// write_only.set_status(1);
+///
+/// assert_eq!(registers.control, 0b101);
+/// assert_eq!(registers.status, 1);
+/// ```
+///
+/// As the generated setters perform aligned writes, `#[repr(packed)]` structs are rejected:
+///
+/// ```compile_fail
+/// use write_only::WriteOnlyFields;
+///
+/// #[derive(WriteOnlyFields)]
+/// #[repr(C, packed)]
+/// struct Header {
+///     tag: u8,
+///     len: u32,
+/// }
+/// ```
+#[proc_macro_derive(WriteOnlyFields)]
+pub fn derive_write_only_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_write_only_fields(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_write_only_fields(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`WriteOnlyFields` does not support generic structs",
+        ));
+    }
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        let mut is_packed = false;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("packed") {
+                is_packed = true;
+            }
+            // Skip any arguments, such as the `N` in `packed(N)` or `align(N)`.
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<TokenStream2>()?;
+            }
+            Ok(())
+        })?;
+
+        if is_packed {
+            return Err(Error::new_spanned(
+                attr,
+                "`WriteOnlyFields` does not support `#[repr(packed)]` structs",
+            ));
+        }
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    input,
+                    "`WriteOnlyFields` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "`WriteOnlyFields` can only be derived for structs",
+            ))
+        }
+    };
+
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let trait_ident = format_ident!("{}WriteOnlyFields", ident);
+
+    let mut declarations = Vec::new();
+    let mut non_volatile = Vec::new();
+    let mut volatile = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let field_ty = &field.ty;
+        let setter = format_ident!("set_{}", field_ident);
+        let doc = format!("Sets the `{field_ident}` field to the given value.");
+
+        declarations.push(quote! {
+            #[doc = #doc]
+            fn #setter(&mut self, value: #field_ty);
+        });
+
+        non_volatile.push(quote! {
+            #[inline]
+            fn #setter(&mut self, value: #field_ty) {
+                // SAFETY: the offset is that of a field of type `#field_ty`.
+                let mut field = unsafe {
+                    self.project_unchecked::<#field_ty>(::core::mem::offset_of!(#ident, #field_ident))
+                };
+                ::write_only::Put::put(&mut field, value);
+            }
+        });
+
+        volatile.push(quote! {
+            #[inline]
+            fn #setter(&mut self, value: #field_ty) {
+                // SAFETY: the offset is that of a field of type `#field_ty`.
+                let mut field = unsafe {
+                    self.project_unchecked::<#field_ty>(::core::mem::offset_of!(#ident, #field_ident))
+                };
+                ::write_only::Write::write(&mut field, value);
+            }
+        });
+    }
+
+    let trait_doc = format!("Per-field write-only access to [`{ident}`].");
+
+    Ok(quote! {
+        #[doc = #trait_doc]
+        #vis trait #trait_ident {
+            #(#declarations)*
+        }
+
+        impl<'a> #trait_ident for ::write_only::WriteOnlyRef<'a, #ident> {
+            #(#non_volatile)*
+        }
+
+        impl<'a> #trait_ident for ::write_only::VolatileWriteOnlyRef<'a, #ident> {
+            #(#volatile)*
+        }
+    })
+}
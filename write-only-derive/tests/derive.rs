@@ -0,0 +1,71 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::rc::Rc;
+
+use write_only::{prelude::*, WriteOnlyFields};
+
+#[derive(WriteOnlyFields)]
+#[repr(C)]
+struct Header {
+    tag: u8,
+    len: u32,
+    flags: u16,
+}
+
+#[derive(WriteOnlyFields)]
+struct Shared {
+    id: u64,
+    name: Rc<str>,
+}
+
+#[test]
+fn set_fields() {
+    let mut header = Header {
+        tag: 0,
+        len: 0,
+        flags: 0,
+    };
+
+    let mut write_only = WriteOnlyRef::from(&mut header);
+    write_only.set_len(42);
+    write_only.set_flags(0xbeef);
+
+    assert_eq!(header.tag, 0);
+    assert_eq!(header.len, 42);
+    assert_eq!(header.flags, 0xbeef);
+}
+
+#[test]
+fn set_fields_volatile() {
+    let mut header = Header {
+        tag: 0,
+        len: 0,
+        flags: 0,
+    };
+
+    let mut write_only = VolatileWriteOnlyRef::from(&mut header);
+    write_only.set_tag(7);
+    write_only.set_flags(1);
+
+    assert_eq!(header.tag, 7);
+    assert_eq!(header.len, 0);
+    assert_eq!(header.flags, 1);
+}
+
+#[test]
+fn set_field_drops_old_value() {
+    let old_name: Rc<str> = Rc::from("old");
+    let mut shared = Shared {
+        id: 1,
+        name: Rc::clone(&old_name),
+    };
+
+    let mut write_only = WriteOnlyRef::from(&mut shared);
+    write_only.set_name(Rc::from("new"));
+
+    assert_eq!(Rc::strong_count(&old_name), 1);
+    assert_eq!(shared.id, 1);
+    assert_eq!(&*shared.name, "new");
+}